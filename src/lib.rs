@@ -1,13 +1,59 @@
 mod list_items;
+mod storage;
 mod utils;
-use std::path::Path;
-use std::fs::read_dir;
+use std::env::current_dir;
+use std::path::{Path, PathBuf};
+use std::fs::{copy, create_dir_all, metadata, read_dir, read_to_string, remove_file, write};
 use std::io;
-use chrono::NaiveDate;
-use crate::list_items::structs::ToDoList;
+use std::io::BufRead;
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use crate::list_items::enums::{Priority, SearchMode, SortOrder, ToDoSelectionError};
+use crate::list_items::structs::{ItemBuilder, ToDoList};
+use crate::storage::structs::FileStorage;
+use crate::storage::traits::Storage;
+use crate::utils::functions::{check_directory, parse_iso_date, sort_list};
+
+/// Number of times a numeric prompt accepts invalid input before it gives up.
+pub const MAX_INPUT_ATTEMPTS: u32 = 3;
+
+/// Flag that is set when the user interrupts the program and checked by the menu loops.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Marks the program as interrupted so that the menu loops save the open list and return.
+/// The function can be registered as a handler for keyboard interrupts (Ctrl-C) and is
+/// called automatically when the terminal input is closed (Ctrl-D).
+pub fn request_interrupt() {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+/// Checks whether the program was interrupted. The flag stays set so that all
+/// menu loops return one after another.
+/// 
+/// # Returns
+/// * `bool`: Is `true` if an interrupt was requested
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+/// Flag that pre-answers all confirmation prompts affirmatively, e.g. for scripted sessions.
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the automatic confirmation of all confirmation prompts.
+/// Interactive sessions keep the default (`false`) and are prompted as usual.
+/// 
+/// # Arguments
+/// * assume_yes : bool - Set to `true` to confirm all prompts without reading input
+pub fn set_assume_yes(assume_yes: bool) {
+    ASSUME_YES.store(assume_yes, Ordering::SeqCst);
+}
 
 /// Retrieves user input from the terminal and stores it inside a String value.
 /// 
+/// If the terminal input was closed, an interrupt is requested (see `request_interrupt`).
+/// 
 /// # Returns
 /// * `String`: The trimmed user input that was submitted via the terminal.
 /// 
@@ -15,86 +61,197 @@ use crate::list_items::structs::ToDoList;
 /// The function panics if the io module failed to read the terminal input line
 pub fn get_user_input() -> String {
     let mut input = String::new();
-    io::stdin()
+    let bytes_read = io::stdin()
         .read_line(&mut input)
         .expect("Failed to read line");
+    if bytes_read == 0 {
+        request_interrupt();
+    }
     input.trim().to_string()
 }
 
+/// Collects trimmed lines from a reader until a blank line or the end of the input is reached.
+/// A line that cannot be read ends the input as well.
+/// 
+/// # Arguments
+/// * reader : impl BufRead - Source of the lines
+/// 
+/// # Returns
+/// * `Vec<String>`: The lines read before the blank line
+fn read_lines_until_blank(reader: impl BufRead) -> Vec<String> {
+    reader.lines()
+        .map_while(Result::ok)
+        .map(|line| line.trim().to_string())
+        .take_while(|line| !line.is_empty())
+        .collect()
+}
+
+/// Retrieves multiple lines of user input from the terminal, e.g. a pasted list.
+/// The input ends with a blank line or the end of the input (EOF).
+/// 
+/// # Returns
+/// * `Vec<String>`: The trimmed lines that were submitted via the terminal
+pub fn read_multiline_input() -> Vec<String> {
+    read_lines_until_blank(io::stdin().lock())
+}
+
+/// Parses a numeric user input. An empty input is replaced with a default value.
+/// 
+/// # Arguments
+/// * input : &str - Submitted user input
+/// * default : T - Value used for an empty input
+/// 
+/// # Returns
+/// * `Option<T>`: The parsed or default value, `None` if the input is not a number
+fn parse_or_default<T: FromStr>(input: &str, default: T) -> Option<T> {
+    let input = input.trim();
+    if input.is_empty() {
+        Some(default)
+    } else {
+        input.parse().ok()
+    }
+}
+
+/// Repeatedly reads input until it can be parsed or the maximum number of attempts is reached.
+/// This prevents endless loops when the input is closed or automated input keeps failing.
+/// The error message of every failed conversion is printed before the input is read again.
+/// 
+/// # Arguments
+/// * read_input : impl FnMut() -> String - Source of the input, e.g. `get_user_input`
+/// * parse : impl Fn(&str) -> Result<T, String> - Conversion of the input into the expected value
+/// * max_attempts : u32 - Number of inputs that are read before giving up
+/// 
+/// # Returns
+/// * `Option<T>`: The parsed value or `None` if no valid value was submitted
+fn read_with_retries<T>(mut read_input: impl FnMut() -> String, parse: impl Fn(&str) -> Result<T, String>, max_attempts: u32) -> Option<T> {
+    for _ in 0..max_attempts {
+        let input = read_input();
+        if is_interrupted() {
+            return None;
+        }
+        match parse(&input) {
+            Ok(value) => return Some(value),
+            Err(e) => println!("{}", e),
+        }
+    }
+    println!("No valid value was submitted after {} attempts", max_attempts);
+    None
+}
+
+/// Prints a confirmation prompt and asks the user to confirm it with 'Y'.
+/// If confirmations are assumed (see `set_assume_yes`), no input is read.
+/// 
+/// # Arguments
+/// * message : &str - Prompt that describes the action to confirm
+/// 
+/// # Returns
+/// * `bool`: Is `true` if the action was confirmed
+pub fn confirm(message: &str) -> bool {
+    confirm_with(message, ASSUME_YES.load(Ordering::SeqCst), get_user_input)
+}
+
+/// Prints a confirmation prompt and reads the answer unless confirmations are assumed.
+/// 
+/// # Arguments
+/// * message : &str - Prompt that describes the action to confirm
+/// * assume_yes : bool - Confirms the prompt without reading input if set to `true`
+/// * read_input : impl FnOnce() -> String - Source of the answer, e.g. `get_user_input`
+/// 
+/// # Returns
+/// * `bool`: Is `true` if the prompt was assumed or answered with 'Y'
+fn confirm_with(message: &str, assume_yes: bool, read_input: impl FnOnce() -> String) -> bool {
+    println!("{}", message);
+    if assume_yes {
+        println!("Confirmed automatically");
+        return true;
+    }
+    read_input().to_lowercase().trim().eq("y")
+}
+
+/// Retrieves a number from the terminal. Invalid input is requested again
+/// up to `MAX_INPUT_ATTEMPTS` times in total.
+/// 
+/// # Returns
+/// * `Option<T>`: The submitted number or `None` if no valid number was submitted
+pub fn get_number_input<T: FromStr>() -> Option<T> {
+    read_with_retries(get_user_input, |input| input.trim().parse().map_err(|_| "Please enter a number".to_string()), MAX_INPUT_ATTEMPTS)
+}
+
 /// Uses user input to create a tuple that can be used when a date field should be populated.
-/// The function asks the user to input 3 integer values. The first represents a year,
-/// the second a month, and the third a day. 
+/// The function first accepts a complete ISO date like `2024-05-01`. If no valid ISO date is
+/// submitted, it asks the user to input 3 integer values. The first represents a year,
+/// the second a month, and the third a day. Every prompt shows the respective value of
+/// today's date, which is used if the input is left empty.
 /// At the end, the function validates whether the submitted values can be used to create
 /// a valid NaiveDate struct.
-/// If not, the function will return to its start and loop again.
+/// If not, the function will return to its start and loop again. Each value is requested
+/// up to `MAX_INPUT_ATTEMPTS` times before the function gives up.
 /// 
 /// # Returns
-/// * `(i32, u32, u32)`: A tuple that represents, year, month, and day
-pub fn enter_date_value() -> (i32, u32, u32) {
-    loop {
-        let mut ymd: (i32, u32, u32) = (0,0,0);
-        'year: loop {
-            println!("Please enter a numeric year value");
-            let input = get_user_input();
-            let input: i32 = match input.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("Please enter a number");
-                    continue;
-                }
-            };   
-            ymd.0 = input;
-            break 'year;
-        }
-        'month: loop {
-            println!("Please enter a numeric month value");
-            let input = get_user_input();
-            let input: u32 = match input.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("Please enter a number");
-                    continue;
-                }
-            };   
-            ymd.1 = input;
-            break 'month;
-        }    
-        'day: loop {
-            println!("Please enter a numeric day value");
-            let input = get_user_input();
-            let input: u32 = match input.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("Please enter a number");
-                    continue;
-                }
-            };   
-            ymd.2 = input;
-            break 'day;
-        }
+/// * `Option<(i32, u32, u32)>`: A tuple that represents, year, month, and day or `None` if
+///   no valid date was submitted
+pub fn enter_date_value() -> Option<(i32, u32, u32)> {
+    println!("Please enter the date as YYYY-MM-DD or press enter to submit year, month, and day separately");
+    let input = get_user_input();
+    if let Some(ymd) = parse_iso_date(&input) {
+        return Some(ymd);
+    } else if !input.is_empty() {
+        println!("{} is not a valid date in the YYYY-MM-DD format", input);
+    }
+    let today = Local::now().date_naive();
+    for _ in 0..MAX_INPUT_ATTEMPTS {
+        println!("Please enter a numeric year value [{}]", today.year());
+        let year = read_with_retries(get_user_input, |input| {
+            parse_or_default(input, today.year()).ok_or_else(|| format!("{} is not a valid year, please enter a number", input.trim()))
+        }, MAX_INPUT_ATTEMPTS)?;
+        println!("Please enter a numeric month value [{}]", today.month());
+        let month = read_with_retries(get_user_input, |input| {
+            parse_or_default(input, today.month())
+                .filter(|month| (1..=12).contains(month))
+                .ok_or_else(|| format!("{} is not a valid month, please enter a number between 1 and 12", input.trim()))
+        }, MAX_INPUT_ATTEMPTS)?;
+        println!("Please enter a numeric day value [{}]", today.day());
+        let day = read_with_retries(get_user_input, |input| {
+            parse_or_default(input, today.day())
+                .filter(|day| (1..=31).contains(day))
+                .ok_or_else(|| format!("{} is not a valid day, please enter a number between 1 and 31", input.trim()))
+        }, MAX_INPUT_ATTEMPTS)?;
         // Check whether the input was valid
-        if NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).is_some() {
-            return ymd;
+        if NaiveDate::from_ymd_opt(year, month, day).is_some() {
+            return Some((year, month, day));
         } else {
             println!("The submitted values could not be converted into a date. Please enter valid integers.");
         }
     }
+    None
 }
 
-/// Lists all files stored in the ./lists folder. 
-/// The function assumes that only list structs are stored in this location.#
+/// Lists all files stored in a folder. 
+/// Files whose names are not valid UTF-8 are skipped and reported in the log.
+/// Hidden files (starting with ".") are skipped as well. If the path is not a directory,
+/// the reason is reported in the log and no files are returned.
+/// 
+/// # Arguments
+/// * path : &Path - Folder to examine
 /// 
 /// # Returns
-/// * `Vec<String>`: A Vector containing the names of all files in the lists folder
-fn summarize_list_files() -> Vec<String> {
-    // The path is expected to lead to the "./lists" folder
-    let path = Path::new("./lists");
+/// * `Vec<String>`: A Vector containing the names of all files in the folder
+fn summarize_files(path: &Path) -> Vec<String> {
     let mut file_list: Vec<String> = vec![];
+    if let Err(e) = check_directory(path) {
+        println!("{}", e);
+        return file_list;
+    }
     match read_dir(path) {
         Ok(entries) => {
             for entry in entries {
                 if let Ok(file) = entry {
-                    file_list.push(file.file_name().into_string().expect("Could not convert OsString to String"));
+                    match file.file_name().into_string() {
+                        // Hidden files such as the trash list are not regular lists
+                        Ok(file_name) if file_name.starts_with('.') => continue,
+                        Ok(file_name) => file_list.push(file_name),
+                        Err(file_name) => println!("The file name {} is not valid UTF-8 and was skipped", file_name.to_string_lossy()),
+                    }
                 } else {
                     println!("A file could not be read: {:?}", entry);
                 }
@@ -105,10 +262,42 @@ fn summarize_list_files() -> Vec<String> {
     file_list
 }
 
+/// Lists all files stored in the ./lists folder. 
+/// The function assumes that only list structs are stored in this location.
+/// 
+/// # Returns
+/// * `Vec<String>`: A Vector containing the names of all files in the lists folder
+fn summarize_list_files() -> Vec<String> {
+    // The path is expected to lead to the "./lists" folder
+    summarize_files(Path::new("./lists"))
+}
+
+/// Sorts file names alphabetically without regard to case. If requested, the files
+/// are instead sorted by their modification time with the most recent file first.
+/// Files whose modification time cannot be read are placed at the end.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the files
+/// * file_list : &mut [String] - Names of the files
+/// * by_modification_time : bool - Sort by modification time instead of by name
+fn sort_file_names(dir: &Path, file_list: &mut [String], by_modification_time: bool) {
+    file_list.sort_by(|a, b| a.to_lowercase().cmp(&b.to_lowercase()).then_with(|| a.cmp(b)));
+    if by_modification_time {
+        file_list.sort_by_cached_key(|file_name| {
+            std::cmp::Reverse(metadata(dir.join(file_name)).and_then(|data| data.modified()).ok())
+        });
+    }
+}
+
 /// Displays the names of files located in the ./lists folder.
-/// The names are directly printed to the standard output.
-pub fn show_all_lists() {
-    let file_list = summarize_list_files();
+/// The names are sorted alphabetically (case-insensitive) or by modification time
+/// and directly printed to the standard output.
+/// 
+/// # Arguments
+/// * by_modification_time : bool - Show the most recently modified lists first
+pub fn show_all_lists(by_modification_time: bool) {
+    let mut file_list = summarize_list_files();
+    sort_file_names(Path::new("./lists"), &mut file_list, by_modification_time);
     if !file_list.is_empty() {
         println!("Known to-do lists:");
         for file_name in file_list {
@@ -119,15 +308,216 @@ pub fn show_all_lists() {
     }
 }
 
+/// Builds a list of all files in a folder in which every name is followed by the number
+/// of open Items of the list, e.g. `- groceries.json (4 open)`. Files that cannot be
+/// loaded as a list are marked with `(?)`.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// * by_modification_time : bool - Show the most recently modified lists first
+/// 
+/// # Returns
+/// * `String`: One line per file
+fn format_lists_with_counts(dir: &Path, by_modification_time: bool) -> String {
+    let mut file_list = summarize_files(dir);
+    sort_file_names(dir, &mut file_list, by_modification_time);
+    let mut output = String::new();
+    for file_name in file_list {
+        match ToDoList::load_from_path(&dir.join(&file_name)) {
+            Ok(list) => output.push_str(&format!("\t- {} ({} open)\n", file_name, list.stats().open)),
+            Err(_) => output.push_str(&format!("\t- {} (?)\n", file_name)),
+        }
+    }
+    output
+}
+
+/// Displays the names of all files located in the ./lists folder together with the
+/// number of open Items of every list. Every list is loaded for this purpose, so
+/// `show_all_lists` should be used if only the names are required.
+/// 
+/// # Arguments
+/// * by_modification_time : bool - Show the most recently modified lists first
+pub fn show_all_lists_with_counts(by_modification_time: bool) {
+    let overview = format_lists_with_counts(Path::new("./lists"), by_modification_time);
+    if !overview.is_empty() {
+        println!("Known to-do lists:");
+        print!("{}", overview);
+    } else {
+        println!("No to-do list was found in ./lists");
+    }
+}
+
+/// Collects the names of all .json lists in a folder that carry a category.
+/// Hidden files and files that cannot be loaded are skipped.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// * category : &str - Category of the collected lists
+/// 
+/// # Returns
+/// * `Vec<String>`: Alphabetically sorted names of the lists carrying the category
+fn lists_with_category_in(dir: &Path, category: &str) -> Vec<String> {
+    list_json_paths(dir)
+        .into_iter()
+        .filter_map(|path| ToDoList::load_from_path(&path).ok())
+        .filter(|list| list.has_category(category))
+        .map(|list| list.get_name().to_string())
+        .collect()
+}
+
+/// Displays the names of all lists in the ./lists folder that carry a category.
+/// 
+/// # Arguments
+/// * category : &str - Category of the displayed lists
+pub fn show_lists_by_category(category: &str) {
+    let lists = lists_with_category_in(Path::new("./lists"), category);
+    if lists.is_empty() {
+        println!("No list with the category {} was found", category.trim());
+        return;
+    }
+    println!("Lists with the category {}:", category.trim());
+    for list_name in lists {
+        println!("\t- {}", list_name);
+    }
+}
+
+/// Builds an overview of all .json lists in a folder in which the item counts
+/// of every list are indented beneath its name. The lists are sorted alphabetically
+/// without regard to case. Files that cannot be loaded are noted instead.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// 
+/// # Returns
+/// * `String`: One line per list name followed by one indented line with its counts
+fn format_lists_detailed(dir: &Path) -> String {
+    let mut file_list = summarize_files(dir);
+    sort_file_names(dir, &mut file_list, false);
+    let mut output = String::new();
+    for file_name in file_list.iter().filter(|file_name| file_name.ends_with(".json")) {
+        output.push_str(&format!("\t- {}\n", file_name));
+        match ToDoList::load_from_path(&dir.join(file_name)) {
+            Ok(list) => output.push_str(&format!("\t\t{}\n", list.stats())),
+            Err(_) => output.push_str("\t\tThe list could not be loaded\n"),
+        }
+    }
+    output
+}
+
+/// Sums the Item counts of all .json lists in a folder.
+/// Hidden files and files that cannot be loaded are skipped.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// 
+/// # Returns
+/// * `(usize, usize, usize, usize)`: Number of lists, total Items, open Items, and overdue Items
+fn aggregate_counts_in(dir: &Path) -> (usize, usize, usize, usize) {
+    let mut counts = (0, 0, 0, 0);
+    for path in list_json_paths(dir) {
+        if let Ok(list) = ToDoList::load_from_path(&path) {
+            let stats = list.stats();
+            counts.0 += 1;
+            counts.1 += stats.total;
+            counts.2 += stats.open;
+            counts.3 += stats.overdue;
+        }
+    }
+    counts
+}
+
+/// Sums the Item counts of all lists in the ./lists folder.
+/// 
+/// # Returns
+/// * `(usize, usize, usize, usize)`: Number of lists, total Items, open Items, and overdue Items
+pub fn aggregate_counts() -> (usize, usize, usize, usize) {
+    aggregate_counts_in(Path::new("./lists"))
+}
+
+/// Displays the names of all lists located in the ./lists folder together
+/// with the number of their total, open, completed, and overdue Items.
+/// Use `show_all_lists` if only the names are required.
+pub fn show_all_lists_detailed() {
+    let overview = format_lists_detailed(Path::new("./lists"));
+    if !overview.is_empty() {
+        println!("Known to-do lists:");
+        print!("{}", overview);
+    } else {
+        println!("No to-do list was found in ./lists");
+    }
+}
+
+/// Prints every field of an Item in a labeled block.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List that contains the Item
+/// * item_name : &str - Name of the Item
+/// 
+/// # Errors
+/// * `ToDoSelectionError::ToDoNotFound`: The list does not contain an Item with the submitted name.
+pub fn show_item_detail(list: &ToDoList, item_name: &str) -> Result<(), ToDoSelectionError> {
+    let item = list.get_item_ref(item_name)?;
+    println!("{}", item.format_details());
+    Ok(())
+}
+
+/// Adds the current completion rate of a list to its history file `.{list name}.history`
+/// in a folder. Each line of the file stores one snapshot as `YYYY-MM-DD,percentage`.
+/// An existing snapshot of the same day is replaced and unreadable lines are skipped.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the history file
+/// * list : &ToDoList - List whose completion rate is recorded
+/// 
+/// # Returns
+/// * `Vec<(NaiveDate, f64)>`: All recorded snapshots ordered by date
+/// 
+/// # Errors
+/// * Returns an error message if the history file cannot be written
+fn record_completion_snapshot_in(dir: &Path, list: &ToDoList) -> Result<Vec<(NaiveDate, f64)>, String> {
+    let path = dir.join(format!(".{}.history", list.get_name()));
+    let (today, percentage) = list.completion_snapshot();
+    let mut history: Vec<(NaiveDate, f64)> = read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (date, percentage) = line.split_once(',')?;
+            Some((NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?, percentage.parse().ok()?))
+        })
+        .filter(|snapshot| snapshot.0 != today)
+        .collect();
+    history.push((today, percentage));
+    history.sort_by_key(|snapshot| snapshot.0);
+    let contents: String = history.iter().map(|snapshot| format!("{},{:.1}\n", snapshot.0.format("%Y-%m-%d"), snapshot.1)).collect();
+    write(&path, contents).map_err(|e| format!("The history of {} could not be saved: {}", list.get_name(), e))?;
+    Ok(history)
+}
+
+/// Adds the current completion rate of a list to its history in the ./lists folder
+/// and prints all recorded snapshots to show the trend.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List whose completion rate is recorded
+pub fn show_completion_trend(list: &ToDoList) {
+    match record_completion_snapshot_in(Path::new("./lists"), list) {
+        Ok(history) => {
+            for (date, percentage) in history {
+                println!("{}: {:>5.1}% {}", date, percentage, "#".repeat((percentage / 5.0).round() as usize));
+            }
+        },
+        Err(e) => println!("{}", e),
+    }
+}
+
 /// Starts a loop that can be used to examine the Items stored in a ToDoList.
 /// It takes user input to select a list and to choose whether all Items or just a
 /// subset should be printed to the standard output.
 pub fn visualize_lists() {
     'list_visualization: loop {
-        show_all_lists();
+        show_all_lists(false);
         println!("Enter the name of a list to examine its contents or 'cancel' to return");
         let list_selection = get_user_input();
-        if list_selection.to_lowercase().trim().eq("cancel") {
+        if is_interrupted() || list_selection.to_lowercase().trim().eq("cancel") {
             break 'list_visualization;
         }    
         if !list_file_exists(&list_selection) {
@@ -135,15 +525,14 @@ pub fn visualize_lists() {
             continue 'list_visualization;
         }
         let list = open_to_do_list(&list_selection).expect("Selected list does not exist");
+        // Number of Items shown in the focus view, kept for the whole session
+        let mut focus_count: usize = 3;
         'item_visualization: loop {
-            println!("Make a selection:\n1: View all items\n2: List open items\n3: List overdue items\n4: Cancel");
-            let input = get_user_input();
-            let input: u32 = match input.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("Please enter a number");
-                    continue 'item_visualization;
-                }
+            println!("Make a selection:\n1: View all items\n2: List open items\n3: List overdue items\n4: View item tree\n5: Export to iCalendar\n6: View items in custom order\n7: List reminders due today\n8: Focus on top open items\n9: View items by age\n10: View items grouped by tag\n11: Check list health\n12: Show item details\n13: View completion trend\n14: Export item as JSON\n15: View due date breakdown\n16: Show oldest open item\n17: View next action per tag\n18: View items by status\n19: List items by color label\n20: Export overdue items\n21: Team view\n22: Search items\n23: Show most overdue items\n24: Forecast completion date\n25: List items due on weekends\n26: List items by minimum priority\n27: Export to HTML\n28: List items in progress\n29: View items by due week\n30: Cancel");
+            let input: u32 = match get_number_input() {
+                Some(num) => num,
+                None if is_interrupted() => break 'item_visualization,
+                None => continue 'item_visualization,
             };
             if input == 1 {
                 list.display_all_items();
@@ -155,6 +544,202 @@ pub fn visualize_lists() {
                 list.display_all_overdue_items();
             }
             if input == 4 {
+                list.display_item_tree();
+            }
+            if input == 5 {
+                let path = format!("./{}.ics", list.get_name());
+                match write(&path, list.export_ics()) {
+                    Ok(_) => println!("The list was exported to {}", path),
+                    Err(e) => println!("The list could not be exported: {}", e),
+                }
+            }
+            if input == 6 {
+                list.display_items_by_order();
+            }
+            if input == 7 {
+                list.display_reminders_due_today();
+            }
+            if input == 8 {
+                println!("Enter the number of items to focus on or leave it empty to keep {}", focus_count);
+                let count_input = get_user_input();
+                if let Ok(count) = count_input.parse::<usize>() {
+                    focus_count = count;
+                } else if !count_input.is_empty() {
+                    println!("Please enter a number");
+                    continue 'item_visualization;
+                }
+                for item in list.top_open_items(focus_count) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 9 {
+                list.display_all_items_by_age();
+            }
+            if input == 10 {
+                list.display_grouped_by_tag();
+            }
+            if input == 11 {
+                let cycles = list.find_dependency_cycles();
+                let undescribed = list.items_missing_description();
+                if cycles.is_empty() && undescribed.is_empty() {
+                    println!("No problems were found");
+                }
+                for cycle in cycles {
+                    println!("Circular dependency: {} -> {}", cycle.join(" -> "), cycle[0]);
+                }
+                for name in undescribed {
+                    println!("Missing description: {}", name);
+                }
+            }
+            if input == 12 {
+                let item_names = list.display_numbered_items();
+                println!("Enter the name or number of the item");
+                let selection = get_user_input();
+                let item_name = resolve_item_selection(&selection, &item_names).unwrap_or(selection);
+                if let Err(e) = show_item_detail(&list, &item_name) {
+                    println!("{}", e);
+                }
+            }
+            if input == 13 {
+                show_completion_trend(&list);
+            }
+            if input == 14 {
+                let item_names = list.display_numbered_items();
+                println!("Enter the name or number of the item");
+                let selection = get_user_input();
+                let item_name = resolve_item_selection(&selection, &item_names).unwrap_or(selection);
+                match list.export_item(&item_name) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => println!("{}", e),
+                }
+            }
+            if input == 15 {
+                println!("{}", list.due_breakdown());
+            }
+            if input == 16 {
+                match list.oldest_open_item() {
+                    Some((_, item)) => println!("This item has been waiting the longest:\n{}", list.format_item(item)),
+                    None => println!("The list has no open items"),
+                }
+            }
+            if input == 17 {
+                let next_actions = list.next_actions_by_tag();
+                if next_actions.is_empty() {
+                    println!("No open item has a tag");
+                }
+                for (tag, item) in sort_list(&next_actions) {
+                    println!("{}: {}", tag, list.format_item(item));
+                }
+            }
+            if input == 18 {
+                let (open_items, completed_items) = list.partition_by_status();
+                println!("Open ({}):", open_items.len());
+                for (_, item) in open_items {
+                    println!("\t{}", list.format_item(item));
+                }
+                println!("Completed ({}):", completed_items.len());
+                for (_, item) in completed_items {
+                    println!("\t{}", list.format_item(item));
+                }
+            }
+            if input == 19 {
+                println!("Enter the color label");
+                let label = get_user_input();
+                let labeled_items = list.filter_by_label(&label);
+                for item in ToDoList::list_all_items(&labeled_items) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 20 {
+                let path = format!("./{}_overdue.json", list.get_name());
+                match list.export_overdue(Path::new(&path)) {
+                    Ok(_) => println!("The overdue items were exported to {}", path),
+                    Err(e) => println!("The overdue items could not be exported: {}", e),
+                }
+            }
+            if input == 21 {
+                let workload = list.workload_by_assignee();
+                if workload.is_empty() {
+                    println!("There are no open items");
+                }
+                for (assignee, count) in sort_list(&workload) {
+                    println!("{}: {} open item(s)", assignee, count);
+                }
+            }
+            if input == 22 {
+                println!("Enter the keywords separated by spaces");
+                let query = get_user_input();
+                println!("Enter 'all' to find items containing all keywords or leave it empty to find items containing any keyword");
+                let mode = if get_user_input().to_lowercase().trim().eq("all") { SearchMode::All } else { SearchMode::Any };
+                let found_items = list.search_items_advanced(&query, mode);
+                if found_items.is_empty() {
+                    println!("No items were found");
+                }
+                for item in ToDoList::list_all_items(&found_items) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 23 {
+                println!("Enter the number of items to show");
+                let Some(count) = get_number_input::<usize>() else {
+                    continue 'item_visualization;
+                };
+                let today = Local::now().date_naive();
+                for (_, item) in list.top_overdue(count) {
+                    let days_overdue = item.get_due_date().map_or(0, |due_date| (today - due_date).num_days());
+                    println!("\n{} day(s) overdue: {}", days_overdue, list.format_item(item));
+                }
+            }
+            if input == 24 {
+                println!("Enter the number of items you complete per day (e.g. 1.5)");
+                let Some(per_day) = get_number_input::<f64>() else {
+                    continue 'item_visualization;
+                };
+                match list.projected_completion(per_day) {
+                    Some(date) => println!("All open items will be completed on {}", date),
+                    None => println!("No completion date can be projected: there are no open items or the rate is not positive"),
+                }
+            }
+            if input == 25 {
+                let weekend_items = list.items_due_on_weekend();
+                if weekend_items.is_empty() {
+                    println!("No open item is due on a weekend");
+                }
+                for item in ToDoList::list_all_items(&weekend_items) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 26 {
+                println!("Enter the minimum priority (Low, Medium, High, or Critical)");
+                let threshold = Priority::from_str(&get_user_input());
+                if threshold == Priority::Invalid {
+                    println!("The priority is not valid");
+                    continue 'item_visualization;
+                }
+                for item in list.items_at_least(&threshold) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 27 {
+                let path = format!("./{}.html", list.get_name());
+                match write(&path, list.export_html()) {
+                    Ok(_) => println!("The list was exported to {}", path),
+                    Err(e) => println!("The list could not be exported: {}", e),
+                }
+            }
+            if input == 28 {
+                let started_items = list.in_progress_items();
+                if started_items.is_empty() {
+                    println!("No item is in progress");
+                }
+                for item in ToDoList::list_all_items(&started_items) {
+                    println!("\n{}", list.format_item(item.1));
+                }
+            }
+            if input == 29 {
+                list.display_grouped_by_due_week();
+            }
+            if input == 30 {
                 break 'item_visualization;
             }            
         }
@@ -162,36 +747,477 @@ pub fn visualize_lists() {
 
 }
 
-/// Checks whether the ./lists folder contains a list with a specific name.
-/// The function checks the list name with and without the .json extension.
+/// Lists all backup files (`*.json.bak`) stored in a folder.
 /// 
 /// # Arguments
-/// * list_name : &str - Name of the ToDoList 
+/// * dir : &Path - Folder that is searched for backup files
 /// 
 /// # Returns
-/// * `bool`: Is `true` if a ToDoList with the same name exists in ./lists
-fn list_file_exists(list_name: &str) -> bool {
-    let list_name = list_name.to_string();
-    let file_name = format!("{}.json", &list_name);
-    summarize_list_files().contains(&list_name) || summarize_list_files().contains(&file_name)
+/// * `Vec<String>`: Alphabetically sorted names of all backup files
+fn find_backups(dir: &Path) -> Vec<String> {
+    let mut backups: Vec<String> = vec![];
+    if let Ok(entries) = read_dir(dir) {
+        for file in entries.flatten() {
+            if let Ok(file_name) = file.file_name().into_string() && file_name.ends_with(".json.bak") {
+                backups.push(file_name);
+            }
+        }
+    }
+    backups.sort();
+    backups
 }
 
-/// Deserializes a list.json file and loads it into a ToDoList struct.
-/// The function processes the name of the list with or withouth the 
-/// .json extension.
+/// Permanently deletes all backup files (`*.json.bak`) stored in a folder.
 /// 
 /// # Arguments
-/// * list_name: &str - Name of the list (file) to load
+/// * dir : &Path - Folder that is cleaned
 /// 
 /// # Returns
-/// * `ToDoList` - The deserialized version of the selected list
+/// * `usize`: Number of deleted backup files
 /// 
 /// # Errors
-/// * Returns an error message if the selected list file does not exist
-pub fn open_to_do_list(list_name: &str) -> Result<ToDoList, String> {
-    let file_name = if list_name.to_lowercase().contains(".") {
-        list_name.to_string()
-    } else {
+/// * Returns the `io::Error` of the first backup file that could not be deleted
+fn remove_backups(dir: &Path) -> Result<usize, io::Error> {
+    let backups = find_backups(dir);
+    for backup in &backups {
+        remove_file(dir.join(backup))?;
+    }
+    Ok(backups.len())
+}
+
+/// Lists all backup files (`*.json.bak`) stored in the ./lists folder.
+/// 
+/// # Returns
+/// * `Vec<String>`: Alphabetically sorted names of all backup files
+pub fn list_backups() -> Vec<String> {
+    find_backups(Path::new("./lists"))
+}
+
+/// Permanently deletes all backup files (`*.json.bak`) stored in the ./lists folder.
+/// 
+/// # Returns
+/// * `usize`: Number of deleted backup files
+/// 
+/// # Errors
+/// * Returns the `io::Error` of the first backup file that could not be deleted
+pub fn clean_backups() -> Result<usize, io::Error> {
+    remove_backups(Path::new("./lists"))
+}
+
+/// Copies the backup of a list (`{name}.json.bak`) over the list file (`{name}.json`) in a folder.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the list and its backup
+/// * list_name : &str - Name of the list with or without the .json extension
+/// 
+/// # Errors
+/// * Returns an error message if no backup exists or it could not be copied
+fn copy_backup(dir: &Path, list_name: &str) -> Result<(), String> {
+    let list_name = list_name.strip_suffix(".json").unwrap_or(list_name);
+    let backup_path = dir.join(format!("{}.json.bak", list_name));
+    if !backup_path.is_file() {
+        return Err(format!("No backup of the list {} was found", list_name));
+    }
+    copy(&backup_path, dir.join(format!("{}.json", list_name)))
+        .map(|_| ())
+        .map_err(|e| format!("The backup of the list {} could not be restored: {}", list_name, e))
+}
+
+/// Restores a list in the ./lists folder from its backup (`{name}.json.bak`).
+/// The user is asked for confirmation before the current list file is overwritten.
+/// 
+/// # Arguments
+/// * list_name : &str - Name of the list with or without the .json extension
+/// 
+/// # Errors
+/// * Returns an error message if no backup exists, the user cancelled the restoration,
+///   or the backup could not be copied
+pub fn restore_backup(list_name: &str) -> Result<(), String> {
+    let dir = Path::new("./lists");
+    let list_name = list_name.strip_suffix(".json").unwrap_or(list_name);
+    if !dir.join(format!("{}.json.bak", list_name)).is_file() {
+        return Err(format!("No backup of the list {} was found", list_name));
+    }
+    if !confirm(&format!("The list {} will be replaced by its backup. Enter 'Y' to confirm", list_name)) {
+        return Err("The restoration was cancelled".to_string());
+    }
+    copy_backup(dir, list_name)
+}
+
+/// Tidies a single list in a folder. The list is reloaded, Items with an empty name
+/// are removed, and the file is rewritten as compact JSON with its Items sorted by name.
+/// A backup of the list (`{name}.json.bak`) is deleted afterwards.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the list
+/// * list_name : &str - Name of the list with or without the .json extension
+/// 
+/// # Returns
+/// * `usize`: Number of removed Items
+/// 
+/// # Errors
+/// * Returns an error message if the list could not be loaded or written or the backup could not be deleted
+fn vacuum_list_in(dir: &Path, list_name: &str) -> Result<usize, String> {
+    let list_name = list_name.strip_suffix(".json").unwrap_or(list_name);
+    let path = dir.join(format!("{}.json", list_name));
+    let mut list = ToDoList::load_from_path(&path)?;
+    let removed = list.vacuum();
+    let json = serde_json::to_string(&list).map_err(|e| format!("JSON serialize error: {}", e))?;
+    write(&path, json).map_err(|e| format!("The list {} could not be written: {}", list_name, e))?;
+    let backup_path = dir.join(format!("{}.json.bak", list_name));
+    if backup_path.is_file() {
+        remove_file(&backup_path).map_err(|e| format!("The backup of the list {} could not be deleted: {}", list_name, e))?;
+    }
+    Ok(removed)
+}
+
+/// Tidies a single list in the ./lists folder. Items with an empty name are removed,
+/// the file is rewritten as compact JSON, and the backup of the list is deleted.
+/// 
+/// # Arguments
+/// * list_name : &str - Name of the list with or without the .json extension
+/// 
+/// # Errors
+/// * Returns an error message if the list could not be loaded or written or the backup could not be deleted
+pub fn vacuum_list(list_name: &str) -> Result<(), String> {
+    let removed = vacuum_list_in(Path::new("./lists"), list_name)?;
+    println!("The list was compacted and {} item(s) with an empty name were removed", removed);
+    Ok(())
+}
+
+/// Compares two lists in a folder and builds a report of their differences. Items are
+/// matched by their names. See `ToDoList::diff`.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// * list_a : &str - Name of the first list with or without the .json extension
+/// * list_b : &str - Name of the second list with or without the .json extension
+/// 
+/// # Returns
+/// * `String`: Items that only exist in the first list, only in the second list, and differ in both lists
+/// 
+/// # Errors
+/// * Returns an error message if one of the lists could not be loaded
+fn compare_lists_in(dir: &Path, list_a: &str, list_b: &str) -> Result<String, String> {
+    let list_a = list_a.strip_suffix(".json").unwrap_or(list_a);
+    let list_b = list_b.strip_suffix(".json").unwrap_or(list_b);
+    let first = ToDoList::load_from_path(&dir.join(format!("{}.json", list_a)))?;
+    let second = ToDoList::load_from_path(&dir.join(format!("{}.json", list_b)))?;
+    let diff = first.diff(&second);
+    if diff.is_empty() {
+        return Ok(format!("The lists {} and {} contain the same items", list_a, list_b));
+    }
+    let or_none = |names: &[String]| if names.is_empty() { "(none)".to_string() } else { names.join(", ") };
+    Ok(format!(
+        "Only in {}: {}\nOnly in {}: {}\nDifferent in both: {}",
+        list_a, or_none(&diff.added), list_b, or_none(&diff.removed), or_none(&diff.modified)
+    ))
+}
+
+/// Compares two lists in the ./lists folder and builds a report of their differences.
+/// 
+/// # Arguments
+/// * list_a : &str - Name of the first list with or without the .json extension
+/// * list_b : &str - Name of the second list with or without the .json extension
+/// 
+/// # Returns
+/// * `String`: Items that only exist in the first list, only in the second list, and differ in both lists
+/// 
+/// # Errors
+/// * Returns an error message if one of the lists could not be loaded
+pub fn compare_lists(list_a: &str, list_b: &str) -> Result<String, String> {
+    compare_lists_in(Path::new("./lists"), list_a, list_b)
+}
+
+/// Name of the list that collects deleted Items.
+const TRASH_LIST_NAME: &str = ".trash";
+
+/// Loads the trash list from a storage backend. A new, empty trash list is
+/// created if none has been stored yet.
+/// 
+/// # Arguments
+/// * storage : &dyn Storage - Storage backend that contains the trash
+/// 
+/// # Returns
+/// * `ToDoList`: The trash list
+/// 
+/// # Errors
+/// * Returns an error message if a stored trash exists but cannot be loaded
+fn load_trash(storage: &dyn Storage) -> Result<ToDoList, String> {
+    if !storage.list().iter().any(|name| name == TRASH_LIST_NAME) {
+        return Ok(ToDoList::new(TRASH_LIST_NAME, "Deleted items"));
+    }
+    ToDoList::load_from_storage(storage, TRASH_LIST_NAME)
+}
+
+/// Moves an Item from a list into the trash list of a storage backend.
+/// The Item is stored under a unique key, see `ToDoList::trash_item`.
+/// The list itself is not saved by this function.
+/// 
+/// # Arguments
+/// * storage : &dyn Storage - Storage backend that contains the trash
+/// * list : &mut ToDoList - List that contains the Item
+/// * item_name : &str - Name of the Item
+/// 
+/// # Errors
+/// * Returns an error message if the Item does not exist or the trash could not be loaded or saved
+fn move_to_trash_in(storage: &dyn Storage, list: &mut ToDoList, item_name: &str) -> Result<(), String> {
+    let mut trash = load_trash(storage)?;
+    list.trash_item(item_name, &mut trash).map_err(|e| e.to_string())?;
+    trash.save_to_storage(storage)
+}
+
+/// Moves an Item from the trash list of a storage backend back into a stored list.
+/// 
+/// # Arguments
+/// * storage : &dyn Storage - Storage backend that contains the trash and the list
+/// * trash_key : &str - Key of the Item in the trash (`"<list>: <name>"`)
+/// * to_list : &str - Name of the list that receives the Item
+/// 
+/// # Errors
+/// * Returns an error message if the Item is not in the trash, the list does not exist
+///   or already contains the Item, or either list could not be loaded or saved
+fn restore_from_trash_in(storage: &dyn Storage, trash_key: &str, to_list: &str) -> Result<(), String> {
+    let mut trash = load_trash(storage)?;
+    let mut target = ToDoList::load_from_storage(storage, to_list)?;
+    trash.restore_trashed_item(trash_key, &mut target).map_err(|e| e.to_string())?;
+    target.save_to_storage(storage)?;
+    trash.save_to_storage(storage)
+}
+
+/// Permanently deletes all Items in the trash list of a storage backend.
+/// A trash that cannot be loaded is deleted as well and counted as empty.
+/// 
+/// # Arguments
+/// * storage : &dyn Storage - Storage backend that contains the trash
+/// 
+/// # Returns
+/// * `usize`: Number of deleted Items
+/// 
+/// # Errors
+/// * Returns an error message if the trash could not be deleted
+fn empty_trash_in(storage: &dyn Storage) -> Result<usize, String> {
+    if !storage.list().iter().any(|name| name == TRASH_LIST_NAME) {
+        return Ok(0);
+    }
+    let count = load_trash(storage).map_or(0, |trash| trash.stats().total);
+    storage.delete(TRASH_LIST_NAME)?;
+    Ok(count)
+}
+
+/// Moves an Item from a list into the trash (./lists/.trash.json) instead of deleting it permanently.
+/// The list itself is not saved by this function.
+/// 
+/// # Arguments
+/// * list : &mut ToDoList - List that contains the Item
+/// * item_name : &str - Name of the Item
+/// 
+/// # Errors
+/// * Returns an error message if the Item does not exist or the trash could not be loaded or saved
+fn move_to_trash(list: &mut ToDoList, item_name: &str) -> Result<(), String> {
+    move_to_trash_in(&FileStorage::new("./lists"), list, item_name)
+}
+
+/// Moves an Item from the trash (./lists/.trash.json) back into a list in the ./lists folder.
+/// 
+/// # Arguments
+/// * trash_key : &str - Key of the Item in the trash (`"<list>: <name>"`)
+/// * to_list : &str - Name of the list that receives the Item
+/// 
+/// # Errors
+/// * Returns an error message if the Item is not in the trash, the list does not exist
+///   or already contains the Item, or either list could not be loaded or saved
+pub fn restore_from_trash(trash_key: &str, to_list: &str) -> Result<(), String> {
+    let to_list = to_list.strip_suffix(".json").unwrap_or(to_list);
+    restore_from_trash_in(&FileStorage::new("./lists"), trash_key, to_list)
+}
+
+/// Permanently deletes all Items in the trash (./lists/.trash.json).
+/// 
+/// # Returns
+/// * `usize`: Number of deleted Items
+/// 
+/// # Errors
+/// * Returns an error message if the trash could not be deleted
+pub fn empty_trash() -> Result<usize, String> {
+    empty_trash_in(&FileStorage::new("./lists"))
+}
+
+/// Displays the Items in the trash and asks the user which Item should be
+/// restored and into which list.
+pub fn manage_trash() {
+    let trash = match load_trash(&FileStorage::new("./lists")) {
+        Ok(trash) => trash,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+    if trash.stats().total == 0 {
+        println!("The trash is empty");
+        return;
+    }
+    let trash_keys = trash.display_numbered_items();
+    println!("Select the item to restore by number or 'cancel' to return");
+    let selection = get_user_input();
+    if selection.to_lowercase().trim().eq("cancel") {
+        return;
+    }
+    let Some(trash_key) = resolve_item_selection(&selection, &trash_keys) else {
+        println!("The selected item does not exist");
+        return;
+    };
+    let origin_list = trash.get_item_ref(&trash_key).ok().and_then(|item| item.get_origin_list().clone());
+    match &origin_list {
+        Some(origin_list) => println!("Enter the name of the target list or leave it empty to restore it to {}", origin_list),
+        None => println!("Enter the name of the target list"),
+    }
+    let mut to_list = get_user_input();
+    if to_list.is_empty() && let Some(origin_list) = origin_list {
+        to_list = origin_list;
+    }
+    match restore_from_trash(&trash_key, &to_list) {
+        Ok(_) => println!("Item {} was restored to {}", trash_key, to_list),
+        Err(e) => println!("The item was not restored: {}", e),
+    }
+}
+
+/// Displays all backup files in the ./lists folder and asks the user
+/// for confirmation before deleting them.
+pub fn manage_backups() {
+    let backups = list_backups();
+    if backups.is_empty() {
+        println!("No backup files were found in ./lists");
+        return;
+    }
+    println!("Backup files:");
+    for backup in &backups {
+        println!("\t- {}", backup);
+    }
+    if confirm(&format!("Enter 'Y' to permanently delete {} backup file(s)", backups.len())) {
+        match clean_backups() {
+            Ok(count) => println!("{} backup file(s) were deleted", count),
+            Err(e) => println!("The backup files could not be deleted: {}", e),
+        }
+    }
+}
+
+/// Collects the paths of all .json lists in a folder, sorted alphabetically without
+/// regard to case. Hidden files are skipped. If the folder cannot be read, no paths
+/// are returned.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// 
+/// # Returns
+/// * `Vec<PathBuf>`: Paths of all visible .json files in the folder
+fn list_json_paths(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = match read_dir(dir) {
+        Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+        Err(_) => vec![],
+    };
+    paths.retain(|path| {
+        let hidden = path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        !hidden && path.extension().is_some_and(|extension| extension == "json")
+    });
+    paths.sort_by_cached_key(|path| (path.to_string_lossy().to_lowercase(), path.clone()));
+    paths
+}
+
+/// Builds an in-memory ToDoList that contains copies of all Items due today
+/// from every .json list in a folder. Item names are prefixed with the name of
+/// their source list. Hidden files and files that cannot be loaded are skipped.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// 
+/// # Returns
+/// * `ToDoList`: A list named "Today" with all Items due today
+fn collect_today_items(dir: &Path) -> ToDoList {
+    let mut today_list = ToDoList::new("Today", "Items due today across all lists");
+    for path in list_json_paths(dir) {
+        if let Ok(list) = ToDoList::load_from_path(&path) {
+            let due_today = list.filter_due_today_items();
+            for (name, item) in ToDoList::list_all_items(&due_today) {
+                let copy_name = format!("{}: {}", list.get_name(), name);
+                if let Err(e) = today_list.add_item_copy(item, &copy_name) {
+                    println!("The item {} could not be added to the Today list: {}", copy_name, e);
+                }
+            }
+        }
+    }
+    today_list
+}
+
+/// Builds a read-only "Today" view with all Items due today from every list in ./lists.
+/// The resulting ToDoList only exists in memory and should not be saved.
+/// 
+/// # Returns
+/// * `ToDoList`: A list named "Today" with all Items due today
+pub fn build_today_list() -> ToDoList {
+    collect_today_items(Path::new("./lists"))
+}
+
+/// Opens the ./lists folder in the file browser of the operating system
+/// (Finder on macOS, Explorer on Windows, and the default application via xdg-open on other systems).
+/// 
+/// The absolute path is built from the working directory and is not canonicalized, because
+/// Explorer cannot open the verbatim paths (`\\?\C:\...`) that canonicalization returns on Windows.
+/// 
+/// # Errors
+/// * Returns an error message if the folder does not exist or the file browser cannot be launched
+pub fn open_lists_folder() -> Result<(), String> {
+    let path = current_dir()
+        .map_err(|e| format!("The lists folder could not be resolved: {}", e))?
+        .join("lists");
+    if !path.is_dir() {
+        return Err(format!("The lists folder {} does not exist", path.display()));
+    }
+    let program = if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    };
+    // The exit status is not checked, Explorer reports a failure even if the folder was opened
+    Command::new(program)
+        .arg(&path)
+        .status()
+        .map(|_| ())
+        .map_err(|e| format!("The lists folder could not be opened with {}: {}", program, e))
+}
+
+/// Checks whether the ./lists folder contains a list with a specific name.
+/// The function checks the list name with and without the .json extension.
+/// 
+/// # Arguments
+/// * list_name : &str - Name of the ToDoList 
+/// 
+/// # Returns
+/// * `bool`: Is `true` if a ToDoList with the same name exists in ./lists
+fn list_file_exists(list_name: &str) -> bool {
+    let list_name = list_name.to_string();
+    let file_name = format!("{}.json", &list_name);
+    summarize_list_files().contains(&list_name) || summarize_list_files().contains(&file_name)
+}
+
+/// Deserializes a list.json file and loads it into a ToDoList struct.
+/// The function processes the name of the list with or withouth the 
+/// .json extension.
+/// 
+/// # Arguments
+/// * list_name: &str - Name of the list (file) to load
+/// 
+/// # Returns
+/// * `ToDoList` - The deserialized version of the selected list
+/// 
+/// # Errors
+/// * Returns an error message if the selected list file does not exist
+pub fn open_to_do_list(list_name: &str) -> Result<ToDoList, String> {
+    let file_name = if list_name.to_lowercase().contains(".") {
+        list_name.to_string()
+    } else {
         format!("{}.json", list_name)
     };   
     if list_file_exists(&file_name) {
@@ -201,6 +1227,35 @@ pub fn open_to_do_list(list_name: &str) -> Result<ToDoList, String> {
     }
 }
 
+/// Loads the most recently modified .json list in a folder. Hidden files are ignored.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that contains the lists
+/// 
+/// # Errors
+/// * Returns an error message if the folder contains no list or the newest list cannot be loaded
+fn open_most_recent_in(dir: &Path) -> Result<ToDoList, String> {
+    let newest = list_json_paths(dir)
+        .into_iter()
+        .filter_map(|path| Some((metadata(&path).and_then(|data| data.modified()).ok()?, path)))
+        .max_by(|x, y| x.0.cmp(&y.0).then_with(|| y.1.cmp(&x.1)));
+    match newest {
+        Some((_, path)) => ToDoList::load_from_path(&path),
+        None => Err(format!("No to-do list was found in {}", dir.display())),
+    }
+}
+
+/// Loads the most recently modified list in the ./lists folder to resume working on it.
+/// 
+/// # Returns
+/// * `ToDoList` - The deserialized version of the newest list
+/// 
+/// # Errors
+/// * Returns an error message if no list exists or the newest list cannot be loaded
+pub fn open_most_recent() -> Result<ToDoList, String> {
+    open_most_recent_in(Path::new("./lists"))
+}
+
 /// Creates a new ToDoList and store it as a .json file in the lists folder.
 /// The function checks whether a list with the suggested name already exists
 /// and will ask the user for confirmation if an existing one should be replaced.
@@ -212,14 +1267,134 @@ pub fn create_to_do_list() {
     if !list_file_exists(&list_name) {
         ToDoList::new(&list_name, &list_description).save_to_do_list();
     } else {
-        println!("A list with the name {} already exists. Enter 'Y' to replace it. \nWarning: All items will be removed.", &list_name); 
-        let user_choice = get_user_input();   
-        if user_choice.to_lowercase().trim().eq("y") {
+        if confirm(&format!("A list with the name {} already exists. Enter 'Y' to replace it. \nWarning: All items will be removed.", &list_name)) {
             ToDoList::new(&list_name, &list_description).save_to_do_list();
         }
     }
 }
 
+/// Checks whether a folder contains no lists yet, either because it does not
+/// exist or because no .json list is stored in it. Hidden lists such as the trash are ignored.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder that is expected to contain the lists
+/// 
+/// # Returns
+/// * `bool`: Is `true` if no list exists in the folder
+fn needs_first_run_setup(dir: &Path) -> bool {
+    !dir.is_dir() || FileStorage::new(dir).list().iter().all(|name| name.starts_with('.'))
+}
+
+/// Creates a "Getting Started" list with a few example Items in a folder.
+/// The folder is created if it does not exist yet.
+/// 
+/// # Arguments
+/// * dir : &Path - Folder in which the list is saved
+/// 
+/// # Errors
+/// * Returns an error message if the folder cannot be created or the list cannot be saved
+fn create_sample_list_in(dir: &Path) -> Result<(), String> {
+    create_dir_all(dir).map_err(|e| format!("The folder {} could not be created: {}", dir.display(), e))?;
+    let mut list = ToDoList::new("Getting Started", "Example list to get to know the application");
+    let today = Local::now().date_naive();
+    let tomorrow = today + Duration::days(1);
+    let items = [
+        ItemBuilder::new()
+            .name("Explore the menu")
+            .description("Open this list and look at the available actions")
+            .priority("High")
+            .due_date((today.year(), today.month(), today.day())),
+        ItemBuilder::new()
+            .name("Complete an item")
+            .description("Mark this item as completed once you are done")
+            .priority("Medium")
+            .due_date((tomorrow.year(), tomorrow.month(), tomorrow.day())),
+        ItemBuilder::new()
+            .name("Create your own list")
+            .description("Use 'Create a new list' in the main menu")
+            .priority("Low")
+            .tag("getting-started"),
+    ];
+    for builder in items {
+        let item = builder.build().map_err(|e| e.to_string())?;
+        list.add_item(item).map_err(|e| e.to_string())?;
+    }
+    list.save_to_storage(&FileStorage::new(dir))
+}
+
+/// Guides new users through the setup when no lists exist in the ./lists folder.
+/// The user is asked whether the folder should be created and whether a sample
+/// "Getting Started" list should be added. Nothing happens if lists already exist.
+pub fn first_run_setup() {
+    let dir = Path::new("./lists");
+    if !needs_first_run_setup(dir) {
+        return;
+    }
+    if dir.exists() && let Err(e) = check_directory(dir) {
+        println!("{}", e);
+        return;
+    }
+    if !dir.is_dir() {
+        if !confirm("No lists folder was found. Enter 'Y' to create ./lists") {
+            return;
+        }
+        if let Err(e) = create_dir_all(dir) {
+            println!("The lists folder could not be created: {}", e);
+            return;
+        }
+    }
+    if confirm("No to-do lists exist yet. Enter 'Y' to create a sample \"Getting Started\" list") {
+        match create_sample_list_in(dir) {
+            Ok(_) => println!("The list \"Getting Started\" was created"),
+            Err(e) => println!("{}", e),
+        }
+    }
+}
+
+/// Maps a user selection to the name of a displayed Item.
+/// The selection is first compared against the Item names. If no name matches, it is
+/// interpreted as the number that was printed in front of an Item.
+/// 
+/// # Arguments
+/// * selection : &str - Item name or number submitted by the user
+/// * item_names : &[String] - Names of the Items in the order they were displayed
+/// 
+/// # Returns
+/// * `Option<String>`: Name of the selected Item or `None` if the selection matches no Item
+fn resolve_item_selection(selection: &str, item_names: &[String]) -> Option<String> {
+    let selection = selection.trim();
+    if item_names.iter().any(|name| name == selection) {
+        return Some(selection.to_string());
+    }
+    match selection.parse::<usize>() {
+        Ok(index) if index >= 1 && index <= item_names.len() => Some(item_names[index - 1].clone()),
+        _ => None,
+    }
+}
+
+/// Maps a comma-separated user selection to the names of displayed Items.
+/// Every entry is resolved like a single selection (see `resolve_item_selection`).
+/// Duplicate selections are only returned once.
+/// 
+/// # Arguments
+/// * selection : &str - Comma-separated Item names or numbers submitted by the user
+/// * item_names : &[String] - Names of the Items in the order they were displayed
+/// 
+/// # Returns
+/// * `(Vec<String>, Vec<String>)`: Names of the selected Items and the entries that match no Item
+fn resolve_item_selections(selection: &str, item_names: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut selected: Vec<String> = vec![];
+    let mut invalid: Vec<String> = vec![];
+    for entry in selection.split(',').map(|entry| entry.trim()).filter(|entry| !entry.is_empty()) {
+        match resolve_item_selection(entry, item_names) {
+            Some(name) if !selected.contains(&name) => selected.push(name),
+            Some(_) => {}
+            None => invalid.push(entry.to_string()),
+        }
+    }
+    (selected, invalid)
+}
+
 /// Attempts to create a new Item witin a ToDoList and saves it in the respective
 /// .json file.
 /// The function checks whether an Item with the same name already exists and will
@@ -233,21 +1408,28 @@ fn create_new_item(list :&mut ToDoList) {
     let item_name = get_user_input();
     println!("Enter the description of the item");
     let item_description = get_user_input();
-    println!("Define the priority of the item (Low, Medium, or High)");
+    if is_interrupted() {
+        return;
+    }
+    let similar_items: Vec<String> = list.find_similar(&item_name, &item_description).into_iter()
+        .filter(|name| **name != item_name)
+        .cloned()
+        .collect();
+    if !similar_items.is_empty() && !confirm(&format!("Warning: Similar items already exist: {}. Enter 'Y' to create the item anyway", similar_items.join(", "))) {
+        return;
+    }
+    println!("Define the priority of the item (Low, Medium, High, or Critical) or leave it empty for the list default ({})", list.get_default_priority());
     let item_priority = get_user_input();
     println!("Enter 'Y' if you would like to assign a due date");
     let item_due_date = if get_user_input().to_lowercase().trim().eq("y") {
-        Some(enter_date_value())
+        enter_date_value()
     } else {
         None
     };
-    let mut replace = false;
-    if list.list_contains_item(&item_name) {
-        println!("An item with the name {} already exists. Enter 'Y' to replace it.", item_name);
-        if get_user_input().to_lowercase().trim().eq("y") {
-            replace = true;
-        }
+    if is_interrupted() {
+        return;
     }
+    let replace = list.list_contains_item(&item_name) && confirm(&format!("An item with the name {} already exists. Enter 'Y' to replace it.", item_name));
     if let Err(e) = list.create_item(&item_name, &item_description, &item_priority, item_due_date, replace) {
         println!("The item was not created: {}", e);
     } else {
@@ -255,58 +1437,194 @@ fn create_new_item(list :&mut ToDoList) {
     }
 }
 
-/// Uses user input to select and modify an Item in the open ToDoList.
-/// The user can choose to set any of the fields in the selected Item and
-/// is able to save the changes inside the respective .json file.
-/// Note that without using the 'save' option, the changes will be reversed
-/// as soon as the Item is closed.
-fn select_and_modify_list(list :&mut ToDoList) {
-    // Loop used to select a list Item
-    'list_modification: loop {
-        println!("Choose an Item to modify or submit 'cancel' to return");
-        println!("Current list:\n{}", &list);
-        list.display_all_items();
-        let item_name = get_user_input();
-        if !list.list_contains_item(&item_name) && !item_name.to_lowercase().trim().eq("cancel") {
-            println!("The list does not contain an Item with name {}. Please submit another value.", &item_name);
-            continue;
-        }
-        if item_name.to_lowercase().trim().eq("cancel") {
-            break 'list_modification;
-        }
-        // Loop used to pick the desired modification in the selected Item
-        'item_modification: loop {
-            println!("Selected Item:\n{}", list.get_item_ref(&item_name).expect("The list Item does not exist"));
-            println!("Choose a property to modify");
-            println!("1: Description\n2: Due Date\n3: Priority\n4: Complete item\n5: Open item\n6: Save changes\n7: Cancel");    
-            let input = get_user_input();
-            let input: u32 = match input.trim().parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    println!("Please enter a number");
-                    continue;
+/// Creates a new Item from a single line of user input and saves it in the respective
+/// .json file. See `ToDoList::quick_add` for the supported tokens.
+/// Note that the change is saved directly and cannot be reversed later.
+/// 
+/// # Arguments
+/// * list :&mut ToDoList - Mutable reference to the ToDoList in which the Item will be created
+fn quick_add_item(list: &mut ToDoList) {
+    println!("Enter the item in one line, e.g. 'Buy milk !high @2024-05-01 #groceries'");
+    let line = get_user_input();
+    if is_interrupted() {
+        return;
+    }
+    if let Err(e) = list.quick_add(&line) {
+        println!("The item was not created: {}", e);
+    } else {
+        ToDoList::save_to_do_list(list);
+    }
+}
+
+/// Creates one Item per pasted line in the open ToDoList. Every line is interpreted
+/// like the quick add input. The list is saved once all lines were processed.
+fn bulk_add_items(list: &mut ToDoList) {
+    println!("Paste one item per line, e.g. 'Buy milk !high @2024-05-01 #groceries', and finish with an empty line");
+    let mut count = 0;
+    for line in read_multiline_input() {
+        match list.quick_add(&line) {
+            Ok(_) => count += 1,
+            Err(e) => println!("The item '{}' was not created: {}", line, e),
+        }
+    }
+    println!("{} item(s) were created", count);
+    if count > 0 {
+        ToDoList::save_to_do_list(list);
+    }
+}
+
+/// Asks the user for a new due date of an Item and repeats the prompt until a valid date was submitted.
+/// 
+/// # Arguments
+/// * list : &mut ToDoList - List that contains the Item
+/// * item_name : &str - Name of the Item
+fn update_due_date_prompt(list: &mut ToDoList, item_name: &str) {
+    loop {
+        println!("Enter the new due date as year, month, day");
+        let Some(new_due_date) = enter_date_value() else {
+            return;
+        };
+        match list.update_item_due_date(item_name, new_due_date) {
+            Err(ToDoSelectionError::InvalidDate) => println!("{}", ToDoSelectionError::InvalidDate),
+            result => break result.expect("The list Item does not exist"),
+        }
+    }
+}
+
+/// Walks through the description, priority, and due date of an Item in sequence.
+/// Every prompt shows the current value, which is kept if the input is left empty.
+/// The list is saved once all fields were processed.
+/// 
+/// # Arguments
+/// * list : &mut ToDoList - List that contains the Item
+/// * item_name : &str - Name of the Item
+fn full_edit_item(list: &mut ToDoList, item_name: &str) {
+    let item = list.get_item_ref(item_name).expect("The list Item does not exist");
+    println!("Enter the new description or leave it empty to keep it [{}]", item.get_description());
+    let new_description = get_user_input();
+    if is_interrupted() {
+        return;
+    }
+    if !new_description.is_empty() {
+        list.update_item_description(item_name, &new_description).expect("The list Item does not exist");
+    }
+    let item = list.get_item_ref(item_name).expect("The list Item does not exist");
+    println!("Enter the new priority (Low, Medium, High, Critical) or leave it empty to keep it [{}]", item.get_priority());
+    let new_priority = get_user_input();
+    if is_interrupted() {
+        return;
+    }
+    if Priority::from_str(&new_priority) != Priority::Invalid {
+        list.update_item_priority(item_name, &new_priority).expect("The list Item does not exist");
+    } else if !new_priority.is_empty() {
+        println!("{} is not a valid priority, the priority was not changed", new_priority);
+    }
+    let item = list.get_item_ref(item_name).expect("The list Item does not exist");
+    println!("Enter 'Y' to change the due date or leave it empty to keep it [{}]", item.due_date_string());
+    if get_user_input().to_lowercase().trim().eq("y") {
+        update_due_date_prompt(list, item_name);
+    }
+    if is_interrupted() {
+        return;
+    }
+    ToDoList::save_to_do_list(list);
+}
+
+/// Saves a list in a storage backend if auto-save is enabled for the list.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List that was changed
+/// * storage : &dyn Storage - Storage backend to save the list in
+/// 
+/// # Returns
+/// * `bool`: Is `true` if the list was saved
+/// 
+/// # Errors
+/// * Returns an error message if the list cannot be serialized or stored
+fn auto_save_in(list: &ToDoList, storage: &dyn Storage) -> Result<bool, String> {
+    if !list.is_auto_save() {
+        return Ok(false);
+    }
+    list.save_to_storage(storage)?;
+    Ok(true)
+}
+
+/// Saves a list in the ./lists folder if auto-save is enabled for the list.
+/// Errors are reported in the log.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List that was changed
+fn auto_save(list: &ToDoList) {
+    if let Err(e) = auto_save_in(list, &FileStorage::new("./lists")) {
+        println!("The list could not be saved automatically: {}", e);
+    }
+}
+
+/// Uses user input to select and modify an Item in the open ToDoList.
+/// The user can choose to set any of the fields in the selected Item and
+/// is able to save the changes inside the respective .json file.
+/// Note that without using the 'save' option, the changes will be reversed
+/// as soon as the Item is closed unless auto-save is enabled for the list.
+fn select_and_modify_list(list :&mut ToDoList) {
+    // Loop used to select a list Item
+    'list_modification: loop {
+        println!("Choose an Item to modify by name or number or submit 'cancel' to return");
+        println!("Current list:\n{}", &list);
+        let item_names = list.display_numbered_items();
+        let selection = get_user_input();
+        if is_interrupted() || selection.to_lowercase().trim().eq("cancel") {
+            break 'list_modification;
+        }
+        let item_name = match resolve_item_selection(&selection, &item_names) {
+            Some(name) => name,
+            None => {
+                println!("The list does not contain an Item with name or number {}. Please submit another value.", &selection);
+                continue;
+            }
+        };
+        // Loop used to pick the desired modification in the selected Item
+        'item_modification: loop {
+            let selected_item = list.get_item_ref(&item_name).expect("The list Item does not exist");
+            println!("Selected Item:\n{}", selected_item);
+            for link in selected_item.get_links() {
+                println!("\tLink: {}", link);
+            }
+            let missing_attachments = selected_item.missing_attachments();
+            for attachment in selected_item.get_attachments() {
+                if missing_attachments.contains(&attachment) {
+                    println!("\tAttachment: {} (Warning: the file no longer exists)", attachment.display());
+                } else {
+                    println!("\tAttachment: {}", attachment.display());
                 }
-            };     
+            }
+            println!("Choose a property to modify");
+            println!("1: Description\n2: Due Date\n3: Priority\n4: Complete item\n5: Open item\n6: Save changes\n7: Parent item\n8: Add prerequisite\n9: Add link\n10: Remove link\n11: Pin/unpin item\n12: Move item up\n13: Move item down\n14: Add attachment\n15: Remove attachment\n16: Reminder\n17: Add comment\n18: Full edit\n19: Color label\n20: Assignee\n21: Defer to next month\n22: Start item\n23: Cancel");    
+            let input: u32 = match get_number_input() {
+                Some(num) => num,
+                None if is_interrupted() => break 'item_modification,
+                None => continue 'item_modification,
+            };
             if input == 1 {
                 println!("Enter the new description");
                 let new_description = get_user_input();
                 list.update_item_description(&item_name, &new_description).expect("The list Item does not exist");
-                continue;
             }
             if input == 2 {
-                println!("Enter the new due date as year, month, day");
-                let new_due_date = enter_date_value();
-                list.update_item_due_date(&item_name, new_due_date).expect("The list Item does not exist");
-                continue;
+                update_due_date_prompt(list, &item_name);
             }
             if input == 3 {
-                println!("Enter the new priority (Low, Medium, High)");
+                println!("Enter the new priority (Low, Medium, High, Critical)");
                 let new_priority = get_user_input();
                 list.update_item_priority(&item_name, &new_priority).expect("The list Item does not exist");
             }
             if input == 4 {
                 // Marks the Item as completed
-                list.close_list_item(&item_name).expect("The list Item does not exist");
+                if let Err(e) = list.close_list_item(&item_name, false) {
+                    println!("{} Open prerequisites: {}", e, list.incomplete_dependencies(&item_name).join(", "));
+                    if confirm("Enter 'Y' to complete the item anyway") {
+                        list.close_list_item(&item_name, true).expect("The list Item does not exist");
+                    }
+                }
             }
             if input == 5 {
                 // Marks the Item as non-completed
@@ -316,55 +1634,251 @@ fn select_and_modify_list(list :&mut ToDoList) {
                 ToDoList::save_to_do_list(list);
             }
             if input == 7 {
+                println!("Enter the name of the parent item or leave it empty to remove the parent");
+                let parent = get_user_input();
+                let parent = if parent.is_empty() { None } else { Some(parent.as_str()) };
+                if let Err(e) = list.update_item_parent(&item_name, parent) {
+                    println!("The parent was not changed: {}", e);
+                }
+            }
+            if input == 8 {
+                println!("Enter the name of the item that has to be completed first");
+                let dependency = get_user_input();
+                if let Err(e) = list.add_item_dependency(&item_name, &dependency) {
+                    println!("The prerequisite was not added: {}", e);
+                }
+            }
+            if input == 9 {
+                println!("Enter the link");
+                let link = get_user_input();
+                list.add_item_link(&item_name, &link).expect("The list Item does not exist");
+            }
+            if input == 10 {
+                println!("Enter the link to remove");
+                let link = get_user_input();
+                if !list.remove_item_link(&item_name, &link).expect("The list Item does not exist") {
+                    println!("The item has no link {}", link);
+                }
+            }
+            if input == 11 {
+                if list.toggle_item_pin(&item_name).expect("The list Item does not exist") {
+                    println!("The item is now pinned");
+                } else {
+                    println!("The item is no longer pinned");
+                }
+            }
+            if input == 12 && !list.move_item_up(&item_name).expect("The list Item does not exist") {
+                println!("The item already is the first item");
+            }
+            if input == 13 && !list.move_item_down(&item_name).expect("The list Item does not exist") {
+                println!("The item already is the last item");
+            }
+            if input == 14 {
+                println!("Enter the path of the file");
+                let path = get_user_input();
+                list.add_item_attachment(&item_name, Path::new(&path)).expect("The list Item does not exist");
+            }
+            if input == 15 {
+                println!("Enter the path of the file to remove");
+                let path = get_user_input();
+                if !list.remove_item_attachment(&item_name, Path::new(&path)).expect("The list Item does not exist") {
+                    println!("The item has no attachment {}", path);
+                }
+            }
+            if input == 16 {
+                println!("Enter the number of days before the due date for the reminder or leave it empty to remove it");
+                let reminder_input = get_user_input();
+                let days_before = if reminder_input.is_empty() {
+                    None
+                } else if let Ok(days) = reminder_input.parse::<u32>() {
+                    Some(days)
+                } else {
+                    println!("Please enter a number");
+                    continue;
+                };
+                list.update_item_reminder(&item_name, days_before).expect("The list Item does not exist");
+            }
+            if input == 17 {
+                println!("Enter the comment");
+                let comment = get_user_input();
+                list.add_item_comment(&item_name, &comment).expect("The list Item does not exist");
+            }
+            if input == 18 {
+                full_edit_item(list, &item_name);
+            }
+            if input == 19 {
+                println!("Enter a color label (e.g. red, blue) or leave it empty to remove the label");
+                let label = get_user_input();
+                let label = if label.is_empty() { None } else { Some(label.as_str()) };
+                list.update_item_label(&item_name, label).expect("The list Item does not exist");
+            }
+            if input == 20 {
+                println!("Enter the name of the assignee or leave it empty to unassign the item");
+                let assignee = get_user_input();
+                let assignee = if assignee.is_empty() { None } else { Some(assignee.as_str()) };
+                list.update_item_assignee(&item_name, assignee).expect("The list Item does not exist");
+            }
+            if input == 21 {
+                list.defer_item_to_next_month(&item_name).expect("The list Item does not exist");
+                let item = list.get_item_ref(&item_name).expect("The list Item does not exist");
+                println!("The item is now due on {}", item.due_date_string());
+            }
+            if input == 22 {
+                list.start_item(&item_name).expect("The list Item does not exist");
+                println!("The item was started today");
+            }
+            if input == 23 {
                 break 'item_modification;
+            }
+            if input != 6 {
+                auto_save(list);
             }                    
         }
     }
 }
 
-/// Permanently deletes a selected Item from the open ToDoList.
+/// Moves a selected Item from the open ToDoList into the trash.
 /// The function will ask for user input to select the Item and then asks 
 /// for a final confirmation before the Item is deleted. 
-/// Note that the change is saved directly and cannot be reversed later.
+/// Note that the change is saved directly and can only be reversed by restoring the Item from the trash.
 fn delete_list_item(list: &mut ToDoList) {
     'item_deletion: loop {
         println!("Current list:\n{}", &list);
-        list.display_all_items();                
-        println!("Select an item to delete or 'cancel' to abort.");
+        let item_names = list.display_numbered_items();
+        println!("Select an item to delete by name or number or 'cancel' to abort.");
         let delete_selection = get_user_input();
-        if delete_selection.to_lowercase().trim().eq("cancel") {
+        if is_interrupted() || delete_selection.to_lowercase().trim().eq("cancel") {
             break 'item_deletion;
         }
-        if !list.list_contains_item(&delete_selection) {
-            println!("The selected item does not exist");
-            continue;
-        }
-        println!("Item {} will be deleted permanently. Enter 'Y' to confirm", &delete_selection);
-        let delete_confirmation = get_user_input();
-        if delete_confirmation.to_lowercase().trim().eq("y") {
-            list.delete_item(&delete_selection).expect("The list Item does not exist");
+        let delete_selection = match resolve_item_selection(&delete_selection, &item_names) {
+            Some(name) => name,
+            None => {
+                println!("The selected item does not exist");
+                continue;
+            }
+        };
+        if confirm(&format!("Item {} will be moved to the trash. Enter 'Y' to confirm", &delete_selection)) {
+            if let Err(e) = move_to_trash(list, &delete_selection) {
+                println!("The item was not deleted: {}", e);
+                continue;
+            }
             ToDoList::save_to_do_list(list);
             break 'item_deletion;
         }
     }
 }
 
+/// Applies an action to multiple Items of the open ToDoList at once.
+/// The user selects the Items with a comma-separated list of names or numbers. Entries
+/// that match no Item are reported and skipped. After a final confirmation, the selected
+/// Items are completed, opened, or moved to the trash and the changes are saved directly.
+fn modify_multiple_items(list: &mut ToDoList) {
+    println!("Current list:\n{}", &list);
+    let item_names = list.display_numbered_items();
+    println!("Select the items by a comma-separated list of names or numbers or submit 'cancel' to return");
+    let selection = get_user_input();
+    if selection.to_lowercase().trim().eq("cancel") {
+        return;
+    }
+    let (selected, invalid) = resolve_item_selections(&selection, &item_names);
+    if !invalid.is_empty() {
+        println!("The following entries match no item and are skipped: {}", invalid.join(", "));
+    }
+    if selected.is_empty() {
+        println!("No item was selected");
+        return;
+    }
+    println!("Selected items: {}", selected.join(", "));
+    println!("Choose an action:\n1: Complete items\n2: Open items\n3: Move items to trash\n4: Cancel");
+    let input: u32 = match get_number_input() {
+        Some(num) => num,
+        None => return,
+    };
+    if !(1..=3).contains(&input) {
+        return;
+    }
+    if !confirm(&format!("The action will be applied to {} item(s). Enter 'Y' to confirm", selected.len())) {
+        return;
+    }
+    for item_name in &selected {
+        let result = match input {
+            1 => list.close_list_item(item_name, false).map_err(|e| e.to_string()),
+            2 => list.open_list_item(item_name).map_err(|e| e.to_string()),
+            _ => move_to_trash(list, item_name),
+        };
+        if let Err(e) = result {
+            println!("Item {} was skipped: {}", item_name, e);
+        }
+    }
+    ToDoList::save_to_do_list(list);
+}
+
+/// Saves the list in the given storage if the interrupt flag is set and the save is confirmed.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List that is currently being edited
+/// * storage : &dyn Storage - Storage that receives the list
+/// * flag : &AtomicBool - Flag that is set by an interrupt
+/// * confirm_save : impl FnOnce() -> bool - Asks whether the list should be saved, only called after an interrupt
+/// 
+/// # Returns
+/// * `Result<bool, String>`: Is `Ok(true)` if the program was interrupted and the list was saved
+/// 
+/// # Errors
+/// * Returns an error message if the list cannot be serialized or stored
+fn save_if_interrupted_in(list: &ToDoList, storage: &dyn Storage, flag: &AtomicBool, confirm_save: impl FnOnce() -> bool) -> Result<bool, String> {
+    if !flag.load(Ordering::SeqCst) || !confirm_save() {
+        return Ok(false);
+    }
+    list.save_to_storage(storage)?;
+    Ok(true)
+}
+
+/// Offers to save the list in the ./lists folder if the program was interrupted so that
+/// no changes are lost before the menu returns.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List that is currently being edited
+/// 
+/// # Returns
+/// * `bool`: Is `true` if the program was interrupted
+fn save_if_interrupted(list: &ToDoList) -> bool {
+    if !is_interrupted() {
+        return false;
+    }
+    let message = format!("The input was interrupted. Enter 'Y' to save the list '{}' before exiting", list.get_name());
+    match save_if_interrupted_in(list, &FileStorage::new("./lists"), &INTERRUPTED, || confirm(&message)) {
+        Ok(true) => println!("The list '{}' was saved", list.get_name()),
+        Ok(false) => println!("The unsaved changes to the list '{}' were discarded", list.get_name()),
+        Err(e) => println!("The list '{}' could not be saved: {}", list.get_name(), e),
+    }
+    true
+}
+
 /// Opens the sub-menu to modify the selected ToDoList. 
 /// The menu asks for user input to add, delete, or alter Items in the selected list. 
 /// The changes are then saved to their respective .json file to make them permanent.
 pub fn modify_to_do_list(mut list: ToDoList) {
     'main: loop {
+        if save_if_interrupted(&list) {
+            break 'main;
+        }
         println!("Current list:\n{}", &list);
         list.display_all_items();
-        println!("Choose an action:\n1: Create new Item\n2: Modify existing Item\n3: Delete item\n4: Cancel");
-        let input = get_user_input();
-        let input: u32 = match input.trim().parse() {
-            Ok(num) => num,
-            Err(_) => {
-                println!("Please enter a number");
-                continue;
+        if list.is_archived() {
+            if !confirm("The list is archived and cannot be changed. Enter 'Y' to unarchive it or anything else to return") {
+                break 'main;
             }
-        };  
+            list.toggle_archived();
+            ToDoList::save_to_do_list(&list);
+            continue;
+        }
+        println!("Choose an action:\n1: Create new Item\n2: Modify existing Item\n3: Delete item\n4: Quick add Item\n5: Escalate overdue Items\n6: Modify multiple Items\n7: Set default priority\n8: Move weekend due dates to Monday\n9: Set sort order\n10: Paste multiple Items\n11: Archive list\n12: Import Item from JSON\n13: Set maximum number of Items\n14: Purge old completed Items\n15: Remove all Items\n16: Show completed Items at the bottom (on/off)\n17: Auto-save Item changes (on/off)\n18: Add or remove category\n19: Rename Items by prefix\n20: Tag Items matching a search\n21: Normalize invalid priorities on load (on/off)\n22: Reject due dates in the past (on/off)\n23: Show relative due dates (on/off)\n24: Cancel");
+        let input: u32 = match get_number_input() {
+            Some(num) => num,
+            // The interrupt is handled at the top of the loop
+            None => continue 'main,
+        };
         if input == 1 {
             create_new_item(&mut list);
         }
@@ -375,6 +1889,161 @@ pub fn modify_to_do_list(mut list: ToDoList) {
             delete_list_item(&mut list);
         }
         if input == 4 {
+            quick_add_item(&mut list);
+        }
+        if input == 5 {
+            let count = list.escalate_overdue();
+            println!("The priority of {} overdue item(s) was raised", count);
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 6 {
+            modify_multiple_items(&mut list);
+        }
+        if input == 7 {
+            println!("Enter the default priority for new items (Low, Medium, High, Critical)");
+            list.set_default_priority(&get_user_input());
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 8 {
+            let count = list.move_due_dates_to_weekdays();
+            println!("The due date of {} item(s) was moved to a weekday", count);
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 9 {
+            println!("Current sort order: {}", list.get_sort_order());
+            println!("Choose a sort order:\n1: Alphabetical\n2: Priority\n3: Due Date\n4: Age");
+            let sort_order = match get_user_input().trim() {
+                "1" => SortOrder::Alphabetical,
+                "2" => SortOrder::Priority,
+                "3" => SortOrder::DueDate,
+                "4" => SortOrder::Age,
+                _ => {
+                    println!("The sort order was not changed");
+                    continue;
+                }
+            };
+            list.set_sort_order(sort_order);
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 10 {
+            bulk_add_items(&mut list);
+        }
+        if input == 11 {
+            list.toggle_archived();
+            ToDoList::save_to_do_list(&list);
+            println!("The list {} is archived and read-only", list.get_name());
+        }
+        if input == 12 {
+            println!("Paste the JSON of the item and finish with an empty line");
+            let json = read_multiline_input().join("\n");
+            let replace = confirm("Enter 'Y' to replace an existing item with the same name");
+            match list.import_item(&json, replace) {
+                Ok(_) => ToDoList::save_to_do_list(&list),
+                Err(e) => println!("The item was not imported: {}", e),
+            }
+        }
+        if input == 13 {
+            println!("Enter the maximum number of items or leave it empty to remove the limit");
+            let max_items = get_user_input();
+            if max_items.is_empty() {
+                list.set_max_items(None);
+            } else if let Ok(max_items) = max_items.parse() {
+                list.set_max_items(Some(max_items));
+            } else {
+                println!("Please enter a number");
+                continue;
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 14 {
+            println!("Enter the cutoff date. Completed items finished before it will be deleted");
+            let Some((year, month, day)) = enter_date_value() else {
+                continue;
+            };
+            let cutoff = NaiveDate::from_ymd_opt(year, month, day).expect("The date was validated on entry");
+            if confirm(&format!("Completed items finished before {} will be deleted permanently. Enter 'Y' to confirm", cutoff)) {
+                let count = list.purge_completed_before(cutoff);
+                println!("{} item(s) were deleted", count);
+                ToDoList::save_to_do_list(&list);
+            }
+        }
+        if input == 15 && confirm(&format!("All items of {} will be deleted permanently. Enter 'Y' to confirm", list.get_name())) {
+            let count = list.clear_all_items();
+            println!("{} item(s) were deleted", count);
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 16 {
+            if list.toggle_completed_last() {
+                println!("Completed items are shown below open items");
+            } else {
+                println!("Completed items are shown in the regular sort order");
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 17 {
+            if list.toggle_auto_save() {
+                println!("Changes to items are saved automatically");
+            } else {
+                println!("Changes to items have to be saved manually");
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 18 {
+            println!("Current categories: {}", list.get_categories().join(", "));
+            println!("Enter a category to add it or enter an assigned category to remove it");
+            let category = get_user_input();
+            if list.remove_category(&category) {
+                println!("The category {} was removed", category);
+            } else if list.add_category(&category) {
+                println!("The category {} was added", category);
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 19 {
+            println!("Enter the prefix of the items to rename (e.g. old:)");
+            let old_prefix = get_user_input();
+            println!("Enter the new prefix (e.g. new:)");
+            let new_prefix = get_user_input();
+            if confirm(&format!("Items starting with '{}' will be renamed to start with '{}'. Enter 'Y' to confirm", old_prefix, new_prefix)) {
+                let count = list.rename_items_with_prefix(&old_prefix, &new_prefix);
+                println!("{} item(s) were renamed", count);
+                ToDoList::save_to_do_list(&list);
+            }
+        }
+        if input == 20 {
+            println!("Enter the keywords of the items to tag");
+            let query = get_user_input();
+            println!("Enter the tag");
+            let tag = get_user_input();
+            let count = list.tag_matching(&query, &tag);
+            println!("{} item(s) were tagged", count);
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 21 {
+            if list.toggle_normalize_on_load() {
+                println!("Invalid priorities are set to Low when the list is loaded");
+            } else {
+                println!("Invalid priorities are only reported when the list is loaded");
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 22 {
+            if list.toggle_reject_due_dates_before_creation() {
+                println!("New items with a due date in the past are rejected");
+            } else {
+                println!("New items with a due date in the past are accepted with a warning");
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 23 {
+            if list.toggle_relative_due_dates() {
+                println!("Due dates are shown relative to today");
+            } else {
+                println!("Due dates are shown as exact dates");
+            }
+            ToDoList::save_to_do_list(&list);
+        }
+        if input == 24 {
             break 'main;
         }
     }
@@ -386,6 +2055,12 @@ mod tests {
     use std::collections::HashMap;
     use crate::utils::functions::*;
     use crate::list_items::structs::*;
+    use crate::list_items::enums::*;
+    use crate::storage::structs::*;
+    use crate::storage::traits::*;
+    use crate::{aggregate_counts_in, read_with_retries, parse_or_default, open_most_recent_in, record_completion_snapshot_in, read_lines_until_blank, collect_today_items, copy_backup, create_sample_list_in, needs_first_run_setup, empty_trash_in, move_to_trash_in, restore_from_trash_in, find_backups, format_lists_detailed, summarize_files, remove_backups, resolve_item_selection, sort_file_names, resolve_item_selections, show_item_detail, save_if_interrupted_in, lists_with_category_in, auto_save_in, compare_lists_in, format_lists_with_counts, confirm_with, vacuum_list_in};
+    use chrono::{Datelike, Duration, Local, NaiveDate};
+    use std::sync::atomic::{AtomicBool, Ordering};
 
     #[test]
     fn it_sorts_hashmap() {
@@ -423,4 +2098,1997 @@ mod tests {
         let item_ref_after = test_list.get_item_ref("test1").unwrap();
         assert_eq!(item_ref_after.get_description(), "Modified Description");
     }
+
+    #[test]
+    fn priority_maps_to_symbol() {
+        assert_eq!(Priority::Low.symbol(), "!");
+        assert_eq!(Priority::Medium.symbol(), "!!");
+        assert_eq!(Priority::High.symbol(), "!!!");
+        assert_eq!(Priority::Critical.symbol(), "!!!!");
+        assert_eq!(Priority::Invalid.symbol(), "?");
+    }
+
+    #[test]
+    fn completed_items_can_be_removed() {
+        let mut test_list = ToDoList::new("retain", "List used to test retain");
+        test_list.create_item("open", "Open Item", "Low", None, false).unwrap();
+        test_list.create_item("done1", "Completed Item", "Low", None, false).unwrap();
+        test_list.create_item("done2", "Completed Item", "High", None, false).unwrap();
+        test_list.close_list_item("done1", false).unwrap();
+        test_list.close_list_item("done2", false).unwrap();
+
+        let removed = test_list.retain(|item| !item.is_completed());
+
+        assert_eq!(removed, 2);
+        assert!(test_list.list_contains_item("open"));
+        assert!(!test_list.list_contains_item("done1"));
+        assert!(!test_list.list_contains_item("done2"));
+    }
+
+    #[test]
+    fn list_versions_can_be_compared() {
+        let mut old_list = ToDoList::new("diff", "List used to test diff");
+        old_list.create_item("kept", "Unchanged Item", "Low", None, false).unwrap();
+        old_list.create_item("changed", "Original Item", "Low", None, false).unwrap();
+        old_list.create_item("removed", "Removed Item", "Low", None, false).unwrap();
+        let mut new_list = ToDoList::new("diff", "List used to test diff");
+        new_list.create_item("kept", "Unchanged Item", "Low", None, false).unwrap();
+        new_list.create_item("changed", "Modified Item", "Low", None, false).unwrap();
+        new_list.create_item("added", "Added Item", "Low", None, false).unwrap();
+
+        let diff = new_list.diff(&old_list);
+
+        assert_eq!(diff.added, vec!["added".to_string()]);
+        assert_eq!(diff.removed, vec!["removed".to_string()]);
+        assert_eq!(diff.modified, vec!["changed".to_string()]);
+        assert_eq!(diff.to_string(), "Added: added\nRemoved: removed\nModified: changed");
+        assert!(new_list.diff(&new_list).is_empty());
+    }
+
+    #[test]
+    fn item_selection_accepts_names_and_numbers() {
+        let item_names = vec!["alpha".to_string(), "beta".to_string(), "2".to_string()];
+
+        assert_eq!(resolve_item_selection("beta", &item_names), Some("beta".to_string()));
+        assert_eq!(resolve_item_selection("1", &item_names), Some("alpha".to_string()));
+        // Exact name matches take precedence over numbers
+        assert_eq!(resolve_item_selection("2", &item_names), Some("2".to_string()));
+        assert_eq!(resolve_item_selection("3", &item_names), Some("2".to_string()));
+        assert_eq!(resolve_item_selection("0", &item_names), None);
+        assert_eq!(resolve_item_selection("4", &item_names), None);
+        assert_eq!(resolve_item_selection("gamma", &item_names), None);
+    }
+
+    #[test]
+    fn quick_add_parses_tokens() {
+        let mut test_list = ToDoList::new("quick_add", "List used to test quick add");
+        test_list.quick_add("Buy milk !high").unwrap();
+        test_list.quick_add("Pay rent @2024-05-01").unwrap();
+        test_list.quick_add("Call mom #family").unwrap();
+        test_list.quick_add("Buy bread !low @2024-05-02 #groceries #bakery").unwrap();
+
+        let item = test_list.get_item_ref("Buy milk").unwrap();
+        assert_eq!(item.get_priority(), &Priority::High);
+        assert_eq!(item.get_due_date(), &None);
+        let item = test_list.get_item_ref("Pay rent").unwrap();
+        assert_eq!(item.get_priority(), &Priority::Medium);
+        assert_eq!(item.get_due_date().unwrap().to_string(), "2024-05-01");
+        let item = test_list.get_item_ref("Call mom").unwrap();
+        assert_eq!(item.get_tags(), &["family".to_string()]);
+        let item = test_list.get_item_ref("Buy bread").unwrap();
+        assert_eq!(item.get_priority(), &Priority::Low);
+        assert_eq!(item.get_due_date().unwrap().to_string(), "2024-05-02");
+        assert_eq!(item.get_tags(), &["groceries".to_string(), "bakery".to_string()]);
+    }
+
+    #[test]
+    fn quick_add_keeps_unrecognized_tokens_in_name() {
+        let mut test_list = ToDoList::new("quick_add", "List used to test quick add");
+        test_list.quick_add("Fix bug !urgent @tomorrow # now").unwrap();
+        assert!(test_list.list_contains_item("Fix bug !urgent @tomorrow # now"));
+
+        assert!(test_list.quick_add("!high #tag").is_err());
+        assert!(test_list.quick_add("Fix bug !urgent @tomorrow # now").is_err());
+    }
+
+    #[test]
+    fn overdue_items_are_escalated() {
+        let mut test_list = ToDoList::new("escalate", "List used to test escalation");
+        test_list.create_item("overdue_low", "Overdue Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("overdue_high", "Overdue Item", "High", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("completed", "Completed Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("upcoming", "Upcoming Item", "Low", Some((2999, 1, 1)), false).unwrap();
+        test_list.close_list_item("completed", false).unwrap();
+
+        assert_eq!(test_list.escalate_overdue(), 1);
+        assert_eq!(test_list.get_item_ref("overdue_low").unwrap().get_priority(), &Priority::Medium);
+        assert_eq!(test_list.get_item_ref("overdue_high").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.get_item_ref("completed").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("upcoming").unwrap().get_priority(), &Priority::Low);
+
+        assert_eq!(test_list.escalate_overdue(), 1);
+        assert_eq!(test_list.get_item_ref("overdue_low").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.escalate_overdue(), 0);
+    }
+
+    #[test]
+    fn backups_are_listed_and_cleaned() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_backups_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("first.json.bak"), "{}").unwrap();
+        std::fs::write(dir.join("second.json.bak"), "{}").unwrap();
+        std::fs::write(dir.join("list.json"), "{}").unwrap();
+
+        assert_eq!(find_backups(&dir), vec!["first.json.bak".to_string(), "second.json.bak".to_string()]);
+        assert_eq!(remove_backups(&dir).unwrap(), 2);
+        assert!(find_backups(&dir).is_empty());
+        assert!(dir.join("list.json").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn item_tree_indents_children() {
+        let mut test_list = ToDoList::new("tree", "List used to test the item tree");
+        test_list.create_item("parent", "Parent Item", "Low", None, false).unwrap();
+        test_list.create_item("child", "Child Item", "Low", None, false).unwrap();
+        test_list.create_item("other", "Other Item", "Low", None, false).unwrap();
+        test_list.update_item_parent("child", Some("parent")).unwrap();
+        assert!(test_list.update_item_parent("child", Some("missing")).is_err());
+
+        let children: Vec<&str> = test_list.children_of("parent").iter().map(|item| item.get_name()).collect();
+        assert_eq!(children, vec!["child"]);
+
+        let lines: Vec<String> = test_list.format_item_tree().lines().map(|line| line.to_string()).collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Name: other"));
+        assert!(lines[1].starts_with("Name: parent"));
+        assert!(lines[2].starts_with("    Name: child"));
+    }
+
+    #[test]
+    fn item_tree_handles_cycles() {
+        let mut test_list = ToDoList::new("tree", "List used to test the item tree");
+        test_list.create_item("a", "First Item", "Low", None, false).unwrap();
+        test_list.create_item("b", "Second Item", "Low", None, false).unwrap();
+        test_list.update_item_parent("a", Some("b")).unwrap();
+        test_list.update_item_parent("b", Some("a")).unwrap();
+
+        let lines: Vec<String> = test_list.format_item_tree().lines().map(|line| line.to_string()).collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("Name: a"));
+        assert!(lines[1].starts_with("    Name: b"));
+    }
+
+    #[test]
+    fn due_date_is_formatted_for_exports() {
+        let mut test_list = ToDoList::new("export", "List used to test export formatting");
+        test_list.create_item("dated", "Item with due date", "Low", Some((2024, 5, 1)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.get_item_ref("dated").unwrap().due_date_string(), "2024-05-01");
+        assert_eq!(test_list.get_item_ref("undated").unwrap().due_date_string(), "(none)");
+    }
+
+    #[test]
+    fn priority_can_be_set_on_filtered_items() {
+        let mut test_list = ToDoList::new("bulk_priority", "List used to test bulk priority updates");
+        test_list.create_item("open", "Open Item", "High", None, false).unwrap();
+        test_list.create_item("done1", "Completed Item", "High", None, false).unwrap();
+        test_list.create_item("done2", "Completed Item", "Medium", None, false).unwrap();
+        test_list.close_list_item("done1", false).unwrap();
+        test_list.close_list_item("done2", false).unwrap();
+
+        let updated = test_list.set_priority_where(|item| item.is_completed(), "Low");
+
+        assert_eq!(updated, 2);
+        assert_eq!(test_list.get_item_ref("open").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.get_item_ref("done1").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("done2").unwrap().get_priority(), &Priority::Low);
+    }
+
+    #[test]
+    fn today_list_aggregates_lists() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_today_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let today = Local::now().date_naive();
+        let today_ymd = Some((today.year(), today.month(), today.day()));
+        let mut work = ToDoList::new("work", "Work list");
+        work.create_item("report", "Write report", "High", today_ymd, false).unwrap();
+        work.create_item("later", "Not due today", "Low", Some((2999, 1, 1)), false).unwrap();
+        let mut home = ToDoList::new("home", "Home list");
+        home.create_item("dishes", "Do the dishes", "Low", today_ymd, false).unwrap();
+        home.create_item("undated", "No due date", "Low", None, false).unwrap();
+        std::fs::write(dir.join("work.json"), serde_json::to_string(&work).unwrap()).unwrap();
+        std::fs::write(dir.join("home.json"), serde_json::to_string(&home).unwrap()).unwrap();
+        let mut trash = ToDoList::new(".trash", "Deleted Items");
+        trash.create_item("deleted", "Trashed Item due today", "Low", today_ymd, false).unwrap();
+        std::fs::write(dir.join(".trash.json"), serde_json::to_string(&trash).unwrap()).unwrap();
+
+        let today_list = collect_today_items(&dir);
+
+        assert_eq!(today_list.get_name(), "Today");
+        assert!(today_list.list_contains_item("work: report"));
+        assert!(today_list.list_contains_item("home: dishes"));
+        assert!(!today_list.list_contains_item("work: later"));
+        assert!(!today_list.list_contains_item("home: undated"));
+        assert!(!today_list.list_contains_item(".trash: deleted"));
+        assert_eq!(today_list.get_item_ref("home: dishes").unwrap().get_name(), "home: dishes");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn completion_is_blocked_by_open_dependencies() {
+        let mut test_list = ToDoList::new("dependencies", "List used to test dependencies");
+        test_list.create_item("prepare", "Prerequisite", "Low", None, false).unwrap();
+        test_list.create_item("execute", "Dependent Item", "Low", None, false).unwrap();
+        test_list.add_item_dependency("execute", "prepare").unwrap();
+        assert!(test_list.add_item_dependency("execute", "missing").is_err());
+
+        assert!(matches!(test_list.close_list_item("execute", false), Err(ToDoSelectionError::DependenciesIncomplete)));
+        assert!(!test_list.get_item_ref("execute").unwrap().is_completed());
+        assert_eq!(test_list.incomplete_dependencies("execute"), vec!["prepare".to_string()]);
+
+        test_list.close_list_item("prepare", false).unwrap();
+        test_list.close_list_item("execute", false).unwrap();
+        assert!(test_list.get_item_ref("execute").unwrap().is_completed());
+    }
+
+    #[test]
+    fn completion_can_be_forced() {
+        let mut test_list = ToDoList::new("dependencies", "List used to test dependencies");
+        test_list.create_item("prepare", "Prerequisite", "Low", None, false).unwrap();
+        test_list.create_item("execute", "Dependent Item", "Low", None, false).unwrap();
+        test_list.add_item_dependency("execute", "prepare").unwrap();
+
+        test_list.close_list_item("execute", true).unwrap();
+        assert!(test_list.get_item_ref("execute").unwrap().is_completed());
+    }
+
+    #[test]
+    fn links_can_be_added_and_removed() {
+        let mut test_list = ToDoList::new("links", "List used to test links");
+        test_list.create_item("ticket", "Item with link", "Low", None, false).unwrap();
+        test_list.add_item_link("ticket", "https://example.com/ticket/1").unwrap();
+        test_list.add_item_link("ticket", "not a url").unwrap();
+
+        assert_eq!(test_list.get_item_ref("ticket").unwrap().get_links(), &["https://example.com/ticket/1".to_string(), "not a url".to_string()]);
+        assert!(test_list.remove_item_link("ticket", "not a url").unwrap());
+        assert!(!test_list.remove_item_link("ticket", "not a url").unwrap());
+        assert_eq!(test_list.get_item_ref("ticket").unwrap().get_links(), &["https://example.com/ticket/1".to_string()]);
+        assert!(test_list.add_item_link("missing", "https://example.com").is_err());
+    }
+
+    #[test]
+    fn list_is_exported_to_ics() {
+        let mut test_list = ToDoList::new("calendar", "List used to test the iCalendar export");
+        test_list.create_item("report", "Write report, then send it", "High", Some((2024, 5, 1)), false).unwrap();
+        test_list.create_item("undated", "No due date", "Low", None, false).unwrap();
+        test_list.close_list_item("undated", false).unwrap();
+
+        let ics = test_list.export_ics();
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(ics.contains("SUMMARY:report\r\nDESCRIPTION:Write report\\, then send it\r\nDUE;VALUE=DATE:20240501\r\nSTATUS:NEEDS-ACTION\r\nEND:VTODO"));
+        assert!(ics.contains("SUMMARY:undated\r\nDESCRIPTION:No due date\r\nSTATUS:COMPLETED\r\nEND:VTODO"));
+    }
+
+    #[test]
+    fn pinned_items_sort_first() {
+        let mut test_list = ToDoList::new("pinned", "List used to test pinned items");
+        test_list.create_item("a_urgent", "Unpinned Item", "High", None, false).unwrap();
+        test_list.create_item("z_pinned", "Pinned Item", "Low", None, false).unwrap();
+        assert!(test_list.toggle_item_pin("z_pinned").unwrap());
+
+        let item_names = test_list.display_numbered_items();
+        assert_eq!(item_names, vec!["z_pinned".to_string(), "a_urgent".to_string()]);
+
+        assert!(!test_list.toggle_item_pin("z_pinned").unwrap());
+        let item_names = test_list.display_numbered_items();
+        assert_eq!(item_names, vec!["a_urgent".to_string(), "z_pinned".to_string()]);
+    }
+
+    #[test]
+    fn numbered_items_follow_the_sort_order() {
+        let mut test_list = ToDoList::new("numbered", "List used to test the numbering order");
+        test_list.create_item("alpha", "Low Item", "Low", None, false).unwrap();
+        test_list.create_item("bravo", "Completed Item", "Critical", None, false).unwrap();
+        test_list.create_item("charlie", "High Item", "High", None, false).unwrap();
+        test_list.close_list_item("bravo", false).unwrap();
+        test_list.set_sort_order(SortOrder::Priority);
+        test_list.toggle_completed_last();
+
+        let item_names = test_list.display_numbered_items();
+
+        assert_eq!(item_names, vec!["charlie".to_string(), "alpha".to_string(), "bravo".to_string()]);
+        assert_eq!(resolve_item_selection("1", &item_names), Some("charlie".to_string()));
+    }
+
+    #[test]
+    fn items_due_within_days_are_counted() {
+        let ymd = |offset: i64| {
+            let date: NaiveDate = Local::now().date_naive() + Duration::days(offset);
+            Some((date.year(), date.month(), date.day()))
+        };
+        let mut test_list = ToDoList::new("due_within", "List used to test upcoming due dates");
+        test_list.create_item("overdue", "Overdue Item", "Low", ymd(-1), false).unwrap();
+        test_list.create_item("today", "Due today", "Low", ymd(0), false).unwrap();
+        test_list.create_item("in_three_days", "Due in three days", "Low", ymd(3), false).unwrap();
+        test_list.create_item("in_seven_days", "Due in seven days", "Low", ymd(7), false).unwrap();
+        test_list.create_item("in_eight_days", "Due in eight days", "Low", ymd(8), false).unwrap();
+        test_list.create_item("completed", "Completed Item", "Low", ymd(1), false).unwrap();
+        test_list.create_item("undated", "No due date", "Low", None, false).unwrap();
+        test_list.close_list_item("completed", false).unwrap();
+
+        assert_eq!(test_list.due_within(7), 3);
+        assert_eq!(test_list.due_within(0), 1);
+        assert_eq!(test_list.due_within(i64::MIN), 0);
+        assert_eq!(test_list.due_within(i64::MAX), 4);
+        assert_eq!(test_list.stats().due_this_week, 3);
+        assert!(test_list.stats().to_string().ends_with("Due this week: 3"));
+        assert!(!test_list.to_string().contains("Due this week"));
+    }
+
+    #[test]
+    fn invalid_priorities_are_normalized() {
+        let mut test_list = ToDoList::new("normalize", "List used to test priority normalization");
+        test_list.create_item("typo", "Misspelled priority", "Hihg", None, false).unwrap();
+        test_list.create_item("valid", "Valid priority", "High", None, false).unwrap();
+        assert_eq!(test_list.get_item_ref("typo").unwrap().get_priority(), &Priority::Invalid);
+
+        assert_eq!(test_list.normalize_priorities(), 1);
+        assert_eq!(test_list.get_item_ref("typo").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("valid").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.normalize_priorities(), 0);
+    }
+
+    #[test]
+    fn invalid_priorities_are_normalized_on_load() {
+        let test_list = ToDoList::load_to_do_list("invalid_priority");
+        assert_eq!(test_list.get_item_ref("typo").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("valid").unwrap().get_priority(), &Priority::High);
+    }
+
+    #[test]
+    fn invalid_priorities_are_kept_on_load_if_disabled() {
+        let path = std::env::temp_dir().join(format!("to_do_list_normalize_{}.json", std::process::id()));
+        let mut test_list = ToDoList::new("normalize", "List used to test the normalization setting");
+        test_list.create_item("typo", "Misspelled priority", "Hihg", None, false).unwrap();
+        assert!(test_list.is_normalize_on_load());
+        assert!(!test_list.toggle_normalize_on_load());
+        std::fs::write(&path, serde_json::to_string(&test_list).unwrap()).unwrap();
+
+        let loaded_list = ToDoList::load_from_path(&path).unwrap();
+
+        assert!(!loaded_list.is_normalize_on_load());
+        assert_eq!(loaded_list.get_item_ref("typo").unwrap().get_priority(), &Priority::Invalid);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn trimmed_duplicates_are_merged() {
+        let json = r#"{
+            "name": "dedupe",
+            "description": "List used to test merging duplicates",
+            "items": {
+                "task": {"name": "task", "description": "Older", "priority": "Low", "creation_date": "2024-01-01", "due_date": null, "completed": false},
+                "task ": {"name": "task ", "description": "Newer", "priority": "High", "creation_date": "2024-02-01", "due_date": null, "completed": false},
+                " other": {"name": " other", "description": "Untrimmed", "priority": "Low", "creation_date": "2024-01-01", "due_date": null, "completed": false}
+            }
+        }"#;
+        let mut test_list: ToDoList = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test_list.dedupe_trimmed(), 1);
+        assert!(!test_list.list_contains_item("task "));
+        assert!(!test_list.list_contains_item(" other"));
+        assert_eq!(test_list.get_item_ref("task").unwrap().get_description(), "Newer");
+        assert_eq!(test_list.get_item_ref("other").unwrap().get_name(), "other");
+        assert_eq!(test_list.dedupe_trimmed(), 0);
+    }
+
+    #[test]
+    fn critical_priority_is_parsed_and_ordered() {
+        assert_eq!(Priority::from_str("critical"), Priority::Critical);
+        assert_eq!(Priority::from_str("Critical"), Priority::Critical);
+        assert_eq!(Priority::Critical.to_string(), "Critical");
+        assert!(Priority::Critical > Priority::High);
+        assert!(Priority::High > Priority::Medium);
+        assert!(Priority::Medium > Priority::Low);
+        assert!(Priority::Low > Priority::Invalid);
+        assert_eq!(Priority::High.escalate(), Priority::High);
+        assert_eq!(Priority::Critical.escalate(), Priority::Critical);
+    }
+
+    #[test]
+    fn existing_priorities_still_load() {
+        let test_list = ToDoList::load_to_do_list("example");
+        assert_eq!(test_list.get_item_ref("test1").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("test3").unwrap().get_priority(), &Priority::High);
+    }
+
+    #[test]
+    fn multiple_items_can_be_selected() {
+        let item_names = vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()];
+
+        let (selected, invalid) = resolve_item_selections("alpha, 3,delta, 9, 1,", &item_names);
+
+        assert_eq!(selected, vec!["alpha".to_string(), "gamma".to_string()]);
+        assert_eq!(invalid, vec!["delta".to_string(), "9".to_string()]);
+    }
+
+    #[test]
+    fn list_is_saved_in_memory_storage() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("memory", "List used to test the in-memory storage");
+        test_list.create_item("task", "Stored Item", "High", Some((2024, 5, 1)), false).unwrap();
+
+        test_list.save_to_storage(&storage).unwrap();
+        assert_eq!(storage.list(), vec!["memory".to_string()]);
+
+        let loaded_list = ToDoList::load_from_storage(&storage, "memory").unwrap();
+        assert!(loaded_list.diff(&test_list).is_empty());
+        assert_eq!(loaded_list.get_description(), "List used to test the in-memory storage");
+    }
+
+    #[test]
+    fn lists_are_deleted_from_memory_storage() {
+        let storage = MemoryStorage::new();
+        ToDoList::new("first", "First list").save_to_storage(&storage).unwrap();
+        ToDoList::new("second", "Second list").save_to_storage(&storage).unwrap();
+
+        storage.delete("first").unwrap();
+
+        assert_eq!(storage.list(), vec!["second".to_string()]);
+        assert!(storage.delete("first").is_err());
+        assert!(ToDoList::load_from_storage(&storage, "first").is_err());
+    }
+
+    #[test]
+    fn item_age_is_calculated() {
+        let mut test_list = ToDoList::new("age", "List used to test item ages");
+        test_list.create_item("new", "Created today", "Low", None, false).unwrap();
+        let item = test_list.get_item_ref("new").unwrap();
+        assert_eq!(item.age_days(), 0);
+        assert!(item.to_string().contains("(0d old)"));
+
+        let old_list = ToDoList::load_to_do_list("example");
+        let expected_age = (Local::now().date_naive() - NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()).num_days();
+        assert_eq!(old_list.get_item_ref("test1").unwrap().age_days(), expected_age);
+    }
+
+    #[test]
+    fn stale_items_are_filtered() {
+        let json = r#"{
+            "name": "stale",
+            "description": "List used to test stale items",
+            "items": {
+                "old_open": {"name": "old_open", "description": "Old open Item", "priority": "Low", "creation_date": "2001-01-01", "due_date": null, "completed": false},
+                "old_done": {"name": "old_done", "description": "Old completed Item", "priority": "Low", "creation_date": "2001-01-01", "due_date": null, "completed": true},
+                "new_open": {"name": "new_open", "description": "New open Item", "priority": "Low", "creation_date": "2999-01-01", "due_date": null, "completed": false}
+            }
+        }"#;
+        let test_list: ToDoList = serde_json::from_str(json).unwrap();
+
+        let stale = test_list.stale_items(30);
+
+        assert_eq!(stale.len(), 1);
+        assert!(stale.contains_key("old_open"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn non_utf8_file_names_are_skipped() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = std::env::temp_dir().join(format!("to_do_list_utf8_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("valid.json"), "{}").unwrap();
+        // Some file systems reject invalid UTF-8 names, in which case only the valid file exists
+        let _ = std::fs::write(dir.join(OsStr::from_bytes(b"invalid\xff.json")), "{}");
+
+        assert_eq!(summarize_files(&dir), vec!["valid.json".to_string()]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn lists_are_summarized_with_counts() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_overview_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut work = ToDoList::new("work", "Work list");
+        work.create_item("overdue", "Overdue Item", "High", Some((2001, 1, 1)), false).unwrap();
+        work.create_item("done", "Completed Item", "Low", None, false).unwrap();
+        work.close_list_item("done", false).unwrap();
+        std::fs::write(dir.join("work.json"), serde_json::to_string(&work).unwrap()).unwrap();
+        std::fs::write(dir.join("broken.json"), "not json").unwrap();
+        let zeta = ToDoList::new("Zeta", "Empty list");
+        std::fs::write(dir.join("Zeta.json"), serde_json::to_string(&zeta).unwrap()).unwrap();
+
+        let overview = format_lists_detailed(&dir);
+
+        assert_eq!(overview, "\t- broken.json\n\t\tThe list could not be loaded\n\t- work.json\n\t\tTotal: 2\tOpen: 1\tCompleted: 1\tOverdue: 1\tDue this week: 0\n\t- Zeta.json\n\t\tTotal: 0\tOpen: 0\tCompleted: 0\tOverdue: 0\tDue this week: 0\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn items_can_be_reordered() {
+        let mut test_list = ToDoList::new("order", "List used to test the custom order");
+        test_list.create_item("first", "First Item", "Low", None, false).unwrap();
+        test_list.create_item("second", "Second Item", "Low", None, false).unwrap();
+        test_list.create_item("third", "Third Item", "Low", None, false).unwrap();
+        let order = |list: &ToDoList| {
+            let mut names = vec!["first", "second", "third"];
+            names.sort_by_key(|name| list.get_item_ref(name).unwrap().get_order());
+            names
+        };
+        assert_eq!(order(&test_list), vec!["first", "second", "third"]);
+
+        assert!(test_list.move_item_up("third").unwrap());
+        assert_eq!(order(&test_list), vec!["first", "third", "second"]);
+        assert!(test_list.move_item_down("first").unwrap());
+        assert_eq!(order(&test_list), vec!["third", "first", "second"]);
+        assert!(!test_list.move_item_up("third").unwrap());
+        assert!(!test_list.move_item_down("second").unwrap());
+        assert!(test_list.move_item_up("missing").is_err());
+    }
+
+    /// Builds a list with one completed Item per submitted day offset relative to today.
+    fn list_completed_on(offsets: &[i64]) -> ToDoList {
+        let items: Vec<String> = offsets.iter().enumerate().map(|(index, offset)| {
+            let date = Local::now().date_naive() + Duration::days(*offset);
+            format!(r#""item{index}": {{"name": "item{index}", "description": "Completed Item", "priority": "Low", "creation_date": "2001-01-01", "due_date": null, "completed": true, "completion_date": "{date}"}}"#)
+        }).collect();
+        let json = format!(r#"{{"name": "streak", "description": "List used to test streaks", "items": {{{}}}}}"#, items.join(","));
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn completions_are_counted_by_day() {
+        let test_list = list_completed_on(&[0, 0, -1, -3]);
+        let completed_by_day = test_list.completed_by_day();
+        let today = Local::now().date_naive();
+
+        assert_eq!(completed_by_day.len(), 3);
+        assert_eq!(completed_by_day[&today], 2);
+        assert_eq!(completed_by_day[&(today - Duration::days(1))], 1);
+        assert_eq!(completed_by_day[&(today - Duration::days(3))], 1);
+    }
+
+    #[test]
+    fn streak_continues_to_today() {
+        assert_eq!(list_completed_on(&[0, -1, -2]).current_streak(), 3);
+    }
+
+    #[test]
+    fn streak_breaks() {
+        assert_eq!(list_completed_on(&[0, -2, -3]).current_streak(), 1);
+        assert_eq!(list_completed_on(&[-1, -2]).current_streak(), 0);
+    }
+
+    #[test]
+    fn completion_date_is_recorded() {
+        let mut test_list = ToDoList::new("completion", "List used to test completion dates");
+        test_list.create_item("task", "Item to complete", "Low", None, false).unwrap();
+        test_list.close_list_item("task", false).unwrap();
+        assert_eq!(test_list.get_item_ref("task").unwrap().get_completion_date(), &Some(Local::now().date_naive()));
+        assert_eq!(test_list.current_streak(), 1);
+        test_list.open_list_item("task").unwrap();
+        assert_eq!(test_list.get_item_ref("task").unwrap().get_completion_date(), &None);
+    }
+
+    #[test]
+    fn backup_is_restored() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_restore_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("list.json"), "corrupted").unwrap();
+        std::fs::write(dir.join("list.json.bak"), "backup").unwrap();
+
+        copy_backup(&dir, "list").unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("list.json")).unwrap(), "backup");
+        assert!(copy_backup(&dir, "missing").is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn blank_priority_uses_list_default() {
+        let mut test_list = ToDoList::new("default_priority", "List used to test the default priority");
+        assert_eq!(test_list.get_default_priority(), &Priority::Medium);
+        test_list.set_default_priority("High");
+
+        test_list.create_item("blank", "Item without priority", "", None, false).unwrap();
+        test_list.create_item("explicit", "Item with priority", "Low", None, false).unwrap();
+        test_list.quick_add("Quick item").unwrap();
+
+        assert_eq!(test_list.get_item_ref("blank").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.get_item_ref("explicit").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("Quick item").unwrap().get_priority(), &Priority::High);
+    }
+
+    #[test]
+    fn attachments_can_be_added_and_checked() {
+        let mut test_list = ToDoList::new("attachments", "List used to test attachments");
+        test_list.create_item("document", "Item with attachments", "Low", None, false).unwrap();
+        let existing = std::path::Path::new("./lists/example.json");
+        let missing = std::path::Path::new("./lists/does_not_exist.pdf");
+        test_list.add_item_attachment("document", existing).unwrap();
+        test_list.add_item_attachment("document", missing).unwrap();
+
+        let item = test_list.get_item_ref("document").unwrap();
+        assert_eq!(item.get_attachments().len(), 2);
+        assert_eq!(item.missing_attachments(), vec![&missing.to_path_buf()]);
+
+        assert!(test_list.remove_item_attachment("document", missing).unwrap());
+        assert!(test_list.get_item_ref("document").unwrap().missing_attachments().is_empty());
+    }
+
+    #[test]
+    fn vacuum_removes_empty_named_items() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_vacuum_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut test_list = ToDoList::new("list", "List used to test vacuuming");
+        test_list.create_item("b_task", "Valid Item", "Low", None, false).unwrap();
+        test_list.create_item("a_task", "Valid Item", "Low", None, false).unwrap();
+        test_list.create_item(" ", "Item without name", "Low", None, false).unwrap();
+        std::fs::write(dir.join("list.json"), serde_json::to_string_pretty(&test_list).unwrap()).unwrap();
+        std::fs::write(dir.join("list.json.bak"), "backup").unwrap();
+
+        assert_eq!(vacuum_list_in(&dir, "list").unwrap(), 1);
+
+        let json = std::fs::read_to_string(dir.join("list.json")).unwrap();
+        assert!(!json.contains('\n'));
+        assert!(json.find("a_task").unwrap() < json.find("b_task").unwrap());
+        let vacuumed_list = ToDoList::load_from_path(&dir.join("list.json")).unwrap();
+        assert!(!vacuumed_list.list_contains_item(" "));
+        assert!(vacuumed_list.list_contains_item("a_task"));
+        assert!(!dir.join("list.json.bak").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reminder_date_is_calculated() {
+        let mut test_list = ToDoList::new("reminders", "List used to test reminders");
+        test_list.create_item("dated", "Item with due date", "Low", Some((2024, 5, 10)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+        assert_eq!(test_list.get_item_ref("dated").unwrap().reminder_date(), None);
+
+        test_list.update_item_reminder("dated", Some(3)).unwrap();
+        test_list.update_item_reminder("undated", Some(3)).unwrap();
+
+        assert_eq!(test_list.get_item_ref("dated").unwrap().reminder_date(), NaiveDate::from_ymd_opt(2024, 5, 7));
+        assert_eq!(test_list.get_item_ref("undated").unwrap().reminder_date(), None);
+    }
+
+    #[test]
+    fn reminders_due_today_are_filtered() {
+        let in_two_days = Local::now().date_naive() + Duration::days(2);
+        let mut test_list = ToDoList::new("reminders", "List used to test reminders");
+        test_list.create_item("remind", "Reminder due today", "Low", Some((in_two_days.year(), in_two_days.month(), in_two_days.day())), false).unwrap();
+        test_list.create_item("later", "Reminder due tomorrow", "Low", Some((in_two_days.year(), in_two_days.month(), in_two_days.day())), false).unwrap();
+        test_list.update_item_reminder("remind", Some(2)).unwrap();
+        test_list.update_item_reminder("later", Some(1)).unwrap();
+
+        let reminders = test_list.reminders_due_today();
+
+        assert_eq!(reminders.len(), 1);
+        assert!(reminders.contains_key("remind"));
+    }
+
+    #[test]
+    fn top_open_items_are_limited() {
+        let mut test_list = ToDoList::new("focus", "List used to test the focus view");
+        test_list.create_item("low", "Low priority", "Low", None, false).unwrap();
+        test_list.create_item("high", "High priority", "High", None, false).unwrap();
+        test_list.create_item("overdue", "Overdue Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("medium", "Medium priority", "Medium", None, false).unwrap();
+        test_list.create_item("done", "Completed Item", "Critical", None, false).unwrap();
+        test_list.close_list_item("done", false).unwrap();
+
+        let top_items: Vec<&str> = test_list.top_open_items(3).iter().map(|item| item.0.as_str()).collect();
+
+        assert_eq!(top_items, vec!["overdue", "high", "medium"]);
+        assert_eq!(test_list.top_open_items(10).len(), 4);
+    }
+
+    #[test]
+    fn invalid_due_date_is_rejected() {
+        let mut test_list = ToDoList::new("dates", "List used to test date validation");
+        test_list.create_item("task", "Item with due date", "Low", Some((2024, 5, 1)), false).unwrap();
+
+        assert!(matches!(test_list.update_item_due_date("task", (2024, 13, 1)), Err(ToDoSelectionError::InvalidDate)));
+        assert!(matches!(test_list.create_item("invalid", "Invalid due date", "Low", Some((2024, 13, 1)), false), Err(ToDoSelectionError::InvalidDate)));
+        assert!(!test_list.list_contains_item("invalid"));
+        assert_eq!(test_list.get_item_ref("task").unwrap().due_date_string(), "2024-05-01");
+        assert!(matches!(test_list.update_item_due_date("missing", (2024, 5, 2)), Err(ToDoSelectionError::ToDoNotFound)));
+        test_list.update_item_due_date("task", (2024, 5, 2)).unwrap();
+        assert_eq!(test_list.get_item_ref("task").unwrap().due_date_string(), "2024-05-02");
+    }
+
+    #[test]
+    fn multiple_fields_are_updated_at_once() {
+        let mut test_list = ToDoList::new("update", "List used to test partial updates");
+        test_list.create_item("task", "Original description", "Low", None, false).unwrap();
+
+        test_list.apply_update("task", ItemUpdate {
+            description: Some("Updated description".to_string()),
+            priority: Some("High".to_string()),
+            due_date: Some((2024, 5, 1)),
+            completed: Some(true),
+        }).unwrap();
+
+        let item = test_list.get_item_ref("task").unwrap();
+        assert_eq!(item.get_description(), "Updated description");
+        assert_eq!(item.get_priority(), &Priority::High);
+        assert_eq!(item.due_date_string(), "2024-05-01");
+        assert!(item.is_completed());
+
+        let invalid_update = ItemUpdate { description: Some("Ignored".to_string()), due_date: Some((2024, 2, 30)), ..ItemUpdate::default() };
+        assert!(matches!(test_list.apply_update("task", invalid_update), Err(ToDoSelectionError::InvalidDate)));
+        assert_eq!(test_list.get_item_ref("task").unwrap().get_description(), "Updated description");
+        assert!(test_list.apply_update("missing", ItemUpdate::default()).is_err());
+    }
+
+    #[test]
+    fn items_are_sorted_by_age() {
+        let json = r#"{
+            "name": "age",
+            "description": "List used to test sorting by age",
+            "items": {
+                "newest": {"name": "newest", "description": "Newest Item", "priority": "Low", "creation_date": "2024-03-01", "due_date": null, "completed": false},
+                "oldest": {"name": "oldest", "description": "Oldest Item", "priority": "Low", "creation_date": "2024-01-01", "due_date": null, "completed": false},
+                "b_middle": {"name": "b_middle", "description": "Middle Item", "priority": "Low", "creation_date": "2024-02-01", "due_date": null, "completed": false},
+                "a_middle": {"name": "a_middle", "description": "Middle Item", "priority": "Low", "creation_date": "2024-02-01", "due_date": null, "completed": false}
+            }
+        }"#;
+        let test_list: ToDoList = serde_json::from_str(json).unwrap();
+
+        let names: Vec<&str> = test_list.sort_items_by_age().iter().map(|item| item.0.as_str()).collect();
+
+        assert_eq!(names, vec!["oldest", "a_middle", "b_middle", "newest"]);
+    }
+
+    #[test]
+    fn completion_callback_is_invoked() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let completed_names: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+        let callback_names = Rc::clone(&completed_names);
+        let mut test_list = ToDoList::new("callback", "List used to test completion callbacks");
+        test_list.create_item("first", "First Item", "Low", None, false).unwrap();
+        test_list.create_item("second", "Second Item", "Low", None, false).unwrap();
+        test_list.set_on_complete(move |item| callback_names.borrow_mut().push(item.get_name().to_string()));
+
+        test_list.close_list_item("first", false).unwrap();
+        test_list.apply_update("second", ItemUpdate { completed: Some(true), ..ItemUpdate::default() }).unwrap();
+        test_list.open_list_item("first").unwrap();
+
+        assert_eq!(*completed_names.borrow(), vec!["first".to_string(), "second".to_string()]);
+    }
+
+    #[test]
+    fn items_are_grouped_by_tag() {
+        let mut test_list = ToDoList::new("tags", "List used to test tag groups");
+        test_list.quick_add("milk #groceries").unwrap();
+        test_list.quick_add("report #work #urgent").unwrap();
+        test_list.quick_add("call #urgent").unwrap();
+        test_list.quick_add("relax").unwrap();
+        assert_eq!(test_list.all_tags(), vec!["groceries".to_string(), "urgent".to_string(), "work".to_string()]);
+
+        let lines: Vec<String> = test_list.format_grouped_by_tag()
+            .lines()
+            .map(|line| line.split("\tDescription").next().unwrap().to_string())
+            .collect();
+
+        assert_eq!(lines, vec![
+            "Tag: groceries", "\tName: milk",
+            "Tag: urgent", "\tName: call", "\tName: report",
+            "Tag: work", "\tName: report",
+            "Untagged", "\tName: relax",
+        ]);
+    }
+
+    #[test]
+    fn deleted_items_are_moved_to_trash() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("work", "List used to test the trash");
+        test_list.create_item("report", "Deleted Item", "High", None, false).unwrap();
+
+        move_to_trash_in(&storage, &mut test_list, "report").unwrap();
+
+        assert!(!test_list.list_contains_item("report"));
+        let trash = ToDoList::load_from_storage(&storage, ".trash").unwrap();
+        assert_eq!(trash.get_item_ref("work: report").unwrap().get_origin_list(), &Some("work".to_string()));
+        assert!(move_to_trash_in(&storage, &mut test_list, "report").is_err());
+    }
+
+    #[test]
+    fn trashed_items_with_the_same_name_are_kept() {
+        let storage = MemoryStorage::new();
+        let mut work = ToDoList::new("work", "List used to test the trash");
+        let mut home = ToDoList::new("home", "List used to test the trash");
+        work.create_item("report", "First deleted Item", "Low", None, false).unwrap();
+        home.create_item("report", "Second deleted Item", "Low", None, false).unwrap();
+        move_to_trash_in(&storage, &mut work, "report").unwrap();
+        move_to_trash_in(&storage, &mut home, "report").unwrap();
+        work.create_item("report", "Third deleted Item", "Low", None, false).unwrap();
+        move_to_trash_in(&storage, &mut work, "report").unwrap();
+
+        let trash = ToDoList::load_from_storage(&storage, ".trash").unwrap();
+        assert!(trash.list_contains_item("home: report"));
+        assert_eq!(trash.get_item_ref("work: report").unwrap().get_description(), "First deleted Item");
+        assert_eq!(trash.get_item_ref("work: report (2)").unwrap().get_description(), "Third deleted Item");
+    }
+
+    #[test]
+    fn trashed_items_are_restored() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("work", "List used to test the trash");
+        test_list.create_item("report", "Deleted Item", "High", None, false).unwrap();
+        move_to_trash_in(&storage, &mut test_list, "report").unwrap();
+        test_list.save_to_storage(&storage).unwrap();
+
+        restore_from_trash_in(&storage, "work: report", "work").unwrap();
+
+        let restored_list = ToDoList::load_from_storage(&storage, "work").unwrap();
+        assert_eq!(restored_list.get_item_ref("report").unwrap().get_name(), "report");
+        assert_eq!(restored_list.get_item_ref("report").unwrap().get_origin_list(), &None);
+        assert!(!ToDoList::load_from_storage(&storage, ".trash").unwrap().list_contains_item("work: report"));
+        assert!(restore_from_trash_in(&storage, "work: report", "work").is_err());
+    }
+
+    #[test]
+    fn unreadable_trash_is_not_overwritten() {
+        let storage = MemoryStorage::new();
+        storage.save(".trash", "not a list").unwrap();
+        let mut test_list = ToDoList::new("work", "List used to test the trash");
+        test_list.create_item("report", "Deleted Item", "Low", None, false).unwrap();
+
+        assert!(move_to_trash_in(&storage, &mut test_list, "report").is_err());
+        assert!(restore_from_trash_in(&storage, "work: report", "work").is_err());
+
+        assert!(test_list.list_contains_item("report"));
+        assert_eq!(storage.load(".trash").unwrap(), "not a list");
+    }
+
+    #[test]
+    fn trash_is_emptied() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("work", "List used to test the trash");
+        test_list.create_item("first", "Deleted Item", "Low", None, false).unwrap();
+        test_list.create_item("second", "Deleted Item", "Low", None, false).unwrap();
+        move_to_trash_in(&storage, &mut test_list, "first").unwrap();
+        move_to_trash_in(&storage, &mut test_list, "second").unwrap();
+
+        assert_eq!(empty_trash_in(&storage).unwrap(), 2);
+        assert!(storage.list().is_empty());
+        assert_eq!(empty_trash_in(&storage).unwrap(), 0);
+    }
+
+    #[test]
+    fn file_names_are_sorted_case_insensitive() {
+        let mut file_list = vec![
+            "groceries.json".to_string(),
+            "Work.json".to_string(),
+            "chores.json".to_string(),
+            "Archive.json".to_string(),
+        ];
+
+        sort_file_names(std::path::Path::new("./does_not_exist"), &mut file_list, false);
+
+        assert_eq!(file_list, vec!["Archive.json", "chores.json", "groceries.json", "Work.json"]);
+    }
+
+    #[test]
+    fn list_is_converted_to_json_value() {
+        let mut test_list = ToDoList::new("Test", "List used to test JSON values");
+        test_list.create_item("report", "Write the report", "High", None, false).unwrap();
+
+        let value = test_list.to_value();
+
+        assert_eq!(value["name"], "Test");
+        assert_eq!(value["items"]["report"]["description"], "Write the report");
+        assert_eq!(value.pointer("/items/report/priority").unwrap(), "high");
+    }
+
+    #[test]
+    fn saturday_due_date_moves_to_monday() {
+        let mut test_list = ToDoList::new("Test", "List used to test weekday due dates");
+        test_list.create_item("saturday", "Due on a Saturday", "Low", Some((2026, 10, 17)), false).unwrap();
+        let mut item = test_list.get_item_ref("saturday").unwrap().clone();
+
+        assert!(item.move_due_to_next_weekday());
+
+        assert_eq!(item.get_due_date(), &NaiveDate::from_ymd_opt(2026, 10, 19));
+    }
+
+    #[test]
+    fn sunday_due_date_moves_to_monday() {
+        let mut test_list = ToDoList::new("Test", "List used to test weekday due dates");
+        test_list.create_item("sunday", "Due on a Sunday", "Low", Some((2026, 10, 18)), false).unwrap();
+        test_list.create_item("friday", "Due on a Friday", "Low", Some((2026, 10, 16)), false).unwrap();
+        test_list.create_item("undated", "No due date", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.move_due_dates_to_weekdays(), 1);
+
+        assert_eq!(test_list.get_item_ref("sunday").unwrap().get_due_date(), &NaiveDate::from_ymd_opt(2026, 10, 19));
+        assert_eq!(test_list.get_item_ref("friday").unwrap().get_due_date(), &NaiveDate::from_ymd_opt(2026, 10, 16));
+        assert_eq!(test_list.get_item_ref("undated").unwrap().get_due_date(), &None);
+    }
+
+    #[test]
+    fn sample_list_is_created_on_first_run() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_first_run_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(needs_first_run_setup(&dir));
+
+        create_sample_list_in(&dir).unwrap();
+
+        assert!(!needs_first_run_setup(&dir));
+        let list = ToDoList::load_from_path(&dir.join("Getting Started.json")).unwrap();
+        assert_eq!(list.stats().total, 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn counts_are_aggregated_across_lists() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_aggregate_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let storage = FileStorage::new(&dir);
+        let mut first_list = ToDoList::new("first", "First list");
+        first_list.create_item("overdue", "Open and overdue", "Low", Some((2000, 1, 1)), false).unwrap();
+        first_list.create_item("done", "Completed", "Low", None, false).unwrap();
+        first_list.close_list_item("done", false).unwrap();
+        first_list.save_to_storage(&storage).unwrap();
+        let mut second_list = ToDoList::new("second", "Second list");
+        second_list.create_item("open", "Open without due date", "Low", None, false).unwrap();
+        second_list.save_to_storage(&storage).unwrap();
+        std::fs::write(dir.join("broken.json"), "not json").unwrap();
+
+        assert_eq!(aggregate_counts_in(&dir), (2, 3, 2, 1));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sort_order_is_honored_after_reload() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("Test", "List used to test the sort order");
+        test_list.create_item("a_low", "Low priority", "Low", None, false).unwrap();
+        test_list.create_item("b_critical", "Critical priority", "Critical", None, false).unwrap();
+        test_list.create_item("c_high", "High priority", "High", None, false).unwrap();
+        test_list.set_sort_order(SortOrder::Priority);
+        test_list.save_to_storage(&storage).unwrap();
+
+        let loaded_list = ToDoList::load_from_storage(&storage, "Test").unwrap();
+        let names: Vec<&String> = loaded_list.sort_items().into_iter().map(|item| item.0).collect();
+
+        assert_eq!(loaded_list.get_sort_order(), SortOrder::Priority);
+        assert_eq!(names, vec!["b_critical", "c_high", "a_low"]);
+    }
+
+    #[test]
+    fn sort_order_defaults_to_alphabetical() {
+        let test_list: ToDoList = serde_json::from_str(r#"{"name": "Test", "description": "Old list", "items": {}}"#).unwrap();
+
+        assert_eq!(test_list.get_sort_order(), SortOrder::Alphabetical);
+    }
+
+    #[test]
+    fn dependency_cycles_are_reported() {
+        let mut test_list = ToDoList::new("Test", "List used to test dependency cycles");
+        for name in ["a", "b", "c", "d"] {
+            test_list.create_item(name, "Item with prerequisites", "Low", None, false).unwrap();
+        }
+        test_list.add_item_dependency("a", "b").unwrap();
+        test_list.add_item_dependency("b", "c").unwrap();
+        test_list.add_item_dependency("c", "a").unwrap();
+        test_list.add_item_dependency("d", "a").unwrap();
+
+        assert_eq!(test_list.find_dependency_cycles(), vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]]);
+    }
+
+    #[test]
+    fn acyclic_dependencies_are_not_reported() {
+        let mut test_list = ToDoList::new("Test", "List used to test dependency cycles");
+        for name in ["a", "b", "c"] {
+            test_list.create_item(name, "Item with prerequisites", "Low", None, false).unwrap();
+        }
+        test_list.add_item_dependency("a", "b").unwrap();
+        test_list.add_item_dependency("a", "c").unwrap();
+        test_list.add_item_dependency("b", "c").unwrap();
+
+        assert!(test_list.find_dependency_cycles().is_empty());
+    }
+
+    #[test]
+    fn it_sorts_hashmap_ignoring_case_and_accents() {
+        let mut map : HashMap<String, u32> = HashMap::new();
+        for (value, key) in ["banana", "Éclair", "apple", "eclair", "Cherry", "Ångström"].into_iter().enumerate() {
+            map.insert(key.to_string(), value as u32);
+        }
+
+        let folded: Vec<&String> = sort_list_folded(&map).into_iter().map(|item| item.0).collect();
+        let bytewise: Vec<&String> = sort_list(&map).into_iter().map(|item| item.0).collect();
+
+        assert_eq!(folded, vec!["Ångström", "apple", "banana", "Cherry", "eclair", "Éclair"]);
+        assert_eq!(bytewise, vec!["Cherry", "apple", "banana", "eclair", "Ångström", "Éclair"]);
+    }
+
+    #[test]
+    fn item_is_built_with_optional_fields() {
+        let item = ItemBuilder::new()
+            .name("report")
+            .description("Write the quarterly report")
+            .priority("High")
+            .due_date((2026, 12, 31))
+            .tag("work")
+            .tag("writing")
+            .build()
+            .unwrap();
+
+        assert_eq!(item.get_name(), "report");
+        assert_eq!(item.get_description(), "Write the quarterly report");
+        assert_eq!(item.get_priority(), &Priority::High);
+        assert_eq!(item.get_due_date(), &NaiveDate::from_ymd_opt(2026, 12, 31));
+        assert_eq!(item.get_tags(), &vec!["work".to_string(), "writing".to_string()]);
+        assert!(!item.is_completed());
+        assert_eq!(ItemBuilder::new().name("minimal").build().unwrap().get_priority(), &Priority::Medium);
+        assert!(matches!(ItemBuilder::new().name("invalid").due_date((2024, 2, 30)).build(), Err(ToDoSelectionError::InvalidDate)));
+
+        let mut test_list = ToDoList::new("Test", "List used to test the item builder");
+        test_list.add_item(item.clone()).unwrap();
+        assert!(matches!(test_list.add_item(item), Err(ToDoSelectionError::ToDoAlreadyPresent)));
+    }
+
+    #[test]
+    fn item_details_list_every_field() {
+        let mut test_list = ToDoList::new("Test", "List used to test item details");
+        test_list.quick_add("report !high @2026-12-31 #work").unwrap();
+        test_list.add_item_link("report", "https://example.com/ticket").unwrap();
+
+        let details = test_list.get_item_ref("report").unwrap().format_details();
+
+        assert!(details.contains("Name: report\n"));
+        assert!(details.contains("Priority: !!! High\n"));
+        assert!(details.contains("Due Date: 2026-12-31\n"));
+        assert!(details.contains("Tags: work\n"));
+        assert!(details.contains("Parent: (none)\n"));
+        assert!(details.contains("Links: https://example.com/ticket\n"));
+        assert!(show_item_detail(&test_list, "missing").is_err());
+    }
+
+    #[test]
+    fn pasted_lines_end_at_blank_line() {
+        let input = std::io::Cursor::new("Buy milk !high\n  Call mom #family \n\nIgnored line\n");
+
+        assert_eq!(read_lines_until_blank(input), vec!["Buy milk !high", "Call mom #family"]);
+    }
+
+    #[test]
+    fn pasted_lines_end_at_eof() {
+        let input = std::io::Cursor::new("First item\nSecond item");
+
+        assert_eq!(read_lines_until_blank(input), vec!["First item", "Second item"]);
+        assert!(read_lines_until_blank(std::io::Cursor::new("")).is_empty());
+    }
+
+    #[test]
+    fn archived_list_rejects_edits() {
+        let mut test_list = ToDoList::new("Test", "List used to test archiving");
+        test_list.create_item("report", "Write the report", "High", None, false).unwrap();
+
+        assert!(test_list.toggle_archived());
+
+        assert!(matches!(test_list.create_item("new", "New Item", "Low", None, false), Err(ToDoSelectionError::ListArchived)));
+        assert!(matches!(test_list.delete_item("report"), Err(ToDoSelectionError::ListArchived)));
+        assert!(matches!(test_list.update_item_description("report", "Changed"), Err(ToDoSelectionError::ListArchived)));
+        assert!(matches!(test_list.close_list_item("report", false), Err(ToDoSelectionError::ListArchived)));
+        assert_eq!(test_list.get_item_ref("report").unwrap().get_description(), "Write the report");
+
+        assert!(!test_list.toggle_archived());
+        assert!(test_list.update_item_description("report", "Changed").is_ok());
+    }
+
+    #[test]
+    fn archived_list_ignores_bulk_changes() {
+        let mut test_list = ToDoList::new("Test", "List used to test bulk changes of archived lists");
+        test_list.create_item("late", "Overdue Item", "Low", Some((2001, 1, 6)), false).unwrap();
+        test_list.create_item("done", "Completed Item", "Low", None, false).unwrap();
+        test_list.close_list_item("done", false).unwrap();
+        test_list.toggle_archived();
+
+        assert_eq!(test_list.retain(|_| false), 0);
+        assert_eq!(test_list.set_priority_where(|_| true, "High"), 0);
+        assert_eq!(test_list.escalate_overdue(), 0);
+        assert_eq!(test_list.move_due_dates_to_weekdays(), 0);
+        assert_eq!(test_list.normalize_priorities(), 0);
+        assert_eq!(test_list.reset_invalid_to(Priority::High), 0);
+        assert_eq!(test_list.dedupe_trimmed(), 0);
+        assert_eq!(test_list.vacuum(), 0);
+        assert_eq!(test_list.purge_completed_before(NaiveDate::from_ymd_opt(2999, 1, 1).unwrap()), 0);
+        assert_eq!(test_list.clear_all_items(), 0);
+        assert_eq!(test_list.get_item_ref("late").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("late").unwrap().get_due_date(), &NaiveDate::from_ymd_opt(2001, 1, 6));
+        assert!(test_list.list_contains_item("done"));
+    }
+
+    #[test]
+    fn item_copy_is_checked_like_new_items() {
+        let mut source = ToDoList::new("source", "List that provides the Item");
+        source.create_item("report", "Write the report", "High", None, false).unwrap();
+        let item = source.get_item_ref("report").unwrap().clone();
+        let mut test_list = ToDoList::new("Test", "List used to test copied Items");
+
+        assert!(test_list.add_item_copy(&item, "source: report").is_ok());
+        assert_eq!(test_list.get_item_ref("source: report").unwrap().get_name(), "source: report");
+        assert!(matches!(test_list.add_item_copy(&item, "source: report"), Err(ToDoSelectionError::ToDoAlreadyPresent)));
+
+        test_list.toggle_archived();
+        assert!(matches!(test_list.add_item_copy(&item, "copy"), Err(ToDoSelectionError::ListArchived)));
+    }
+
+    #[test]
+    fn completion_snapshot_reports_percentage() {
+        let mut test_list = ToDoList::new("Test", "List used to test completion snapshots");
+        for name in ["a", "b", "c", "d"] {
+            test_list.create_item(name, "Item", "Low", None, false).unwrap();
+        }
+        test_list.close_list_item("a", false).unwrap();
+
+        assert_eq!(test_list.completion_snapshot(), (Local::now().date_naive(), 25.0));
+        assert_eq!(ToDoList::new("Empty", "Empty list").completion_snapshot().1, 0.0);
+    }
+
+    #[test]
+    fn completion_snapshots_are_recorded_once_per_day() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_history_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(".Test.history"), "2020-01-01,10.0\ninvalid line\n").unwrap();
+        let mut test_list = ToDoList::new("Test", "List used to test completion snapshots");
+        test_list.create_item("a", "Item", "Low", None, false).unwrap();
+        test_list.create_item("b", "Item", "Low", None, false).unwrap();
+
+        record_completion_snapshot_in(&dir, &test_list).unwrap();
+        test_list.close_list_item("a", false).unwrap();
+        let history = record_completion_snapshot_in(&dir, &test_list).unwrap();
+
+        let today = Local::now().date_naive();
+        assert_eq!(history, vec![(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), 10.0), (today, 50.0)]);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn most_recent_list_is_opened() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_most_recent_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(open_most_recent_in(&dir).is_err());
+        std::fs::create_dir_all(&dir).unwrap();
+        assert!(open_most_recent_in(&dir).is_err());
+        let storage = FileStorage::new(&dir);
+        ToDoList::new("older", "Edited first").save_to_storage(&storage).unwrap();
+        ToDoList::new("newer", "Edited last").save_to_storage(&storage).unwrap();
+        let now = std::time::SystemTime::now();
+        std::fs::File::options().write(true).open(dir.join("older.json")).unwrap()
+            .set_modified(now - std::time::Duration::from_secs(3600)).unwrap();
+        std::fs::File::options().write(true).open(dir.join("newer.json")).unwrap()
+            .set_modified(now).unwrap();
+
+        assert_eq!(open_most_recent_in(&dir).unwrap().get_name(), "newer");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn due_date_before_creation_is_detected() {
+        let yesterday = Local::now().date_naive() - Duration::days(1);
+        let past = ItemBuilder::new().name("past").due_date((yesterday.year(), yesterday.month(), yesterday.day())).build().unwrap();
+        let future = ItemBuilder::new().name("future").due_date((2999, 1, 1)).build().unwrap();
+
+        assert!(matches!(past.check_due_date(true), Err(ToDoSelectionError::DueDateBeforeCreation)));
+        assert!(past.check_due_date(false).is_ok());
+        assert!(future.check_due_date(true).is_ok());
+        assert!(ItemBuilder::new().name("undated").build().unwrap().check_due_date(true).is_ok());
+    }
+
+    #[test]
+    fn past_due_dates_are_rejected_if_enabled() {
+        let mut test_list = ToDoList::new("Test", "List used to test rejecting past due dates");
+        assert!(!test_list.is_reject_due_dates_before_creation());
+        assert!(test_list.quick_add("accepted @2001-01-01").is_ok());
+
+        assert!(test_list.toggle_reject_due_dates_before_creation());
+        assert!(test_list.quick_add("rejected @2001-01-01").is_err());
+        assert!(matches!(test_list.add_item(ItemBuilder::new().name("built").due_date((2001, 1, 1)).build().unwrap()), Err(ToDoSelectionError::DueDateBeforeCreation)));
+        assert!(matches!(test_list.create_item("created", "Past Item", "Low", Some((2001, 1, 1)), false), Err(ToDoSelectionError::DueDateBeforeCreation)));
+        assert!(test_list.quick_add("future @2999-01-01").is_ok());
+        assert!(test_list.list_contains_item("accepted"));
+        assert!(!test_list.list_contains_item("rejected"));
+        assert!(!test_list.list_contains_item("built"));
+    }
+
+    #[test]
+    fn single_item_is_exported_as_json() {
+        let mut test_list = ToDoList::new("Test", "List used to test item exports");
+        test_list.quick_add("report !high @2026-12-31 #work").unwrap();
+        test_list.create_item("other", "Not exported", "Low", None, false).unwrap();
+
+        let json = test_list.export_item("report").unwrap();
+
+        assert!(json.contains("\"name\": \"report\""));
+        assert!(json.contains("\"priority\": \"high\""));
+        assert!(json.contains("\"due_date\": \"2026-12-31\""));
+        assert!(json.contains("\"work\""));
+        assert!(!json.contains("Not exported"));
+        assert!(test_list.export_item("missing").is_err());
+    }
+
+    #[test]
+    fn exported_item_is_imported() {
+        let mut source_list = ToDoList::new("Source", "List used to test item imports");
+        source_list.quick_add("report !high @2026-12-31 #work").unwrap();
+        let json = source_list.export_item("report").unwrap();
+        let mut target_list = ToDoList::new("Target", "List used to test item imports");
+
+        target_list.import_item(&json, false).unwrap();
+
+        assert_eq!(target_list.get_item_ref("report").unwrap(), source_list.get_item_ref("report").unwrap());
+        assert!(target_list.import_item(&json, false).is_err());
+        assert!(target_list.import_item(&json, true).is_ok());
+        assert!(target_list.import_item("{\"name\": \"broken\"", false).unwrap_err().contains("could not be read"));
+    }
+
+    #[test]
+    fn items_beyond_the_maximum_are_rejected() {
+        let mut test_list = ToDoList::new("Test", "List used to test the maximum number of items");
+        test_list.set_max_items(Some(2));
+
+        assert!(test_list.create_item("first", "Item", "Low", None, false).is_ok());
+        assert!(test_list.create_item("second", "Item", "Low", None, false).is_ok());
+        assert!(matches!(test_list.create_item("third", "Item", "Low", None, false), Err(ToDoSelectionError::ListFull)));
+        assert!(test_list.create_item("second", "Replaced Item", "Low", None, true).is_ok());
+        assert!(test_list.quick_add("third").is_err());
+
+        test_list.set_max_items(None);
+        assert!(test_list.create_item("third", "Item", "Low", None, false).is_ok());
+    }
+
+    #[test]
+    fn empty_date_input_uses_default() {
+        assert_eq!(parse_or_default("", 2026), Some(2026));
+        assert_eq!(parse_or_default("  ", 10u32), Some(10));
+        assert_eq!(parse_or_default(" 12 ", 10u32), Some(12));
+        assert_eq!(parse_or_default("twelve", 10u32), None);
+    }
+
+    #[test]
+    fn open_items_are_counted_by_due_status() {
+        let today = Local::now().date_naive();
+        let yesterday = today - Duration::days(1);
+        let tomorrow = today + Duration::days(1);
+        let mut test_list = ToDoList::new("Test", "List used to test the due breakdown");
+        test_list.create_item("overdue", "Item", "Low", Some((yesterday.year(), yesterday.month(), yesterday.day())), false).unwrap();
+        test_list.create_item("today", "Item", "Low", Some((today.year(), today.month(), today.day())), false).unwrap();
+        test_list.create_item("upcoming", "Item", "Low", Some((tomorrow.year(), tomorrow.month(), tomorrow.day())), false).unwrap();
+        test_list.create_item("later", "Item", "Low", Some((tomorrow.year() + 1, 1, 1)), false).unwrap();
+        test_list.create_item("undated", "Item", "Low", None, false).unwrap();
+        test_list.create_item("completed", "Item", "Low", Some((yesterday.year(), yesterday.month(), yesterday.day())), false).unwrap();
+        test_list.close_list_item("completed", false).unwrap();
+
+        let breakdown = test_list.due_breakdown();
+
+        assert_eq!(breakdown, DueBreakdown { overdue: 1, due_today: 1, upcoming: 2, no_date: 1 });
+        assert_eq!(breakdown.to_string(), "Overdue: 1\tDue today: 1\tUpcoming: 2\tNo due date: 1");
+    }
+
+    #[test]
+    fn comments_are_shown_newest_first() {
+        let mut test_list = ToDoList::new("Test", "List used to test comments");
+        test_list.create_item("report", "Write the report", "High", None, false).unwrap();
+
+        test_list.add_item_comment("report", "Collected the numbers").unwrap();
+        test_list.add_item_comment("report", "Wrote the first draft").unwrap();
+
+        let item = test_list.get_item_ref("report").unwrap();
+        let today = Local::now().date_naive();
+        assert_eq!(item.get_comments(), &[(today, "Collected the numbers".to_string()), (today, "Wrote the first draft".to_string())]);
+        let details = item.format_details();
+        assert!(details.find("Wrote the first draft").unwrap() < details.find("Collected the numbers").unwrap());
+        assert!(test_list.add_item_comment("missing", "Comment").is_err());
+    }
+
+    #[test]
+    fn description_words_are_counted() {
+        let mut test_list = ToDoList::new("Test", "List used to test word counts");
+        test_list.create_item("draft", "Write the  first\tdraft of chapter one", "Low", None, false).unwrap();
+        test_list.create_item("edit", "Edit", "Low", None, false).unwrap();
+        test_list.create_item("empty", "", "Low", None, false).unwrap();
+        test_list.create_item("blank", "   ", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.get_item_ref("draft").unwrap().description_word_count(), 7);
+        assert_eq!(test_list.get_item_ref("empty").unwrap().description_word_count(), 0);
+        assert_eq!(test_list.get_item_ref("blank").unwrap().description_word_count(), 0);
+        assert_eq!(test_list.total_description_words(), 8);
+        assert_eq!(ToDoList::new("Empty", "Empty list").total_description_words(), 0);
+    }
+
+    #[test]
+    fn oldest_open_item_is_found() {
+        let json = r#"{"name": "Test", "description": "List used to test the oldest open item", "items": {
+            "completed": {"name": "completed", "description": "Oldest but completed", "priority": "Low", "creation_date": "2020-01-01", "due_date": null, "completed": true},
+            "b_old": {"name": "b_old", "description": "Old", "priority": "Low", "creation_date": "2021-06-01", "due_date": null, "completed": false},
+            "a_old": {"name": "a_old", "description": "Old", "priority": "Low", "creation_date": "2021-06-01", "due_date": null, "completed": false},
+            "recent": {"name": "recent", "description": "Recent but overdue", "priority": "High", "creation_date": "2024-01-01", "due_date": "2024-01-02", "completed": false}
+        }}"#;
+        let test_list: ToDoList = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test_list.oldest_open_item().unwrap().0, "a_old");
+        assert!(ToDoList::new("Empty", "Empty list").oldest_open_item().is_none());
+    }
+
+    #[test]
+    fn iso_dates_are_parsed() {
+        assert_eq!(parse_iso_date("2024-05-01"), Some((2024, 5, 1)));
+        assert_eq!(parse_iso_date(" 2024-12-31 "), Some((2024, 12, 31)));
+        assert_eq!(parse_iso_date("01.05.2024"), None);
+        assert_eq!(parse_iso_date("2024/05/01"), None);
+        assert_eq!(parse_iso_date(""), None);
+    }
+
+    #[test]
+    fn out_of_range_iso_dates_are_rejected() {
+        assert_eq!(parse_iso_date("2024-13-01"), None);
+        assert_eq!(parse_iso_date("2023-02-29"), None);
+        assert_eq!(parse_iso_date("2024-02-29"), Some((2024, 2, 29)));
+    }
+
+    #[test]
+    fn only_old_completed_items_are_purged() {
+        let json = r#"{"name": "Test", "description": "List used to test purging", "items": {
+            "old_completed": {"name": "old_completed", "description": "Completed long ago", "priority": "Low", "creation_date": "2020-01-01", "due_date": null, "completed": true, "completion_date": "2020-02-01"},
+            "recent_completed": {"name": "recent_completed", "description": "Completed recently", "priority": "Low", "creation_date": "2020-01-01", "due_date": null, "completed": true, "completion_date": "2024-02-01"},
+            "legacy_completed": {"name": "legacy_completed", "description": "Completed without a completion date", "priority": "Low", "creation_date": "2021-01-01", "due_date": null, "completed": true},
+            "old_open": {"name": "old_open", "description": "Still open", "priority": "Low", "creation_date": "2019-01-01", "due_date": null, "completed": false}
+        }}"#;
+        let mut test_list: ToDoList = serde_json::from_str(json).unwrap();
+
+        assert_eq!(test_list.purge_completed_before(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()), 2);
+
+        assert!(test_list.list_contains_item("recent_completed"));
+        assert!(test_list.list_contains_item("old_open"));
+        assert!(!test_list.list_contains_item("old_completed"));
+        assert!(!test_list.list_contains_item("legacy_completed"));
+    }
+
+    #[test]
+    fn next_action_per_tag_is_most_urgent() {
+        let mut test_list = ToDoList::new("Test", "List used to test next actions");
+        test_list.quick_add("call_client !medium #phone #work").unwrap();
+        test_list.quick_add("fix_bug !high @2030-01-02 #work").unwrap();
+        test_list.quick_add("deploy !high @2030-01-01 #work").unwrap();
+        test_list.quick_add("call_mom !low #phone").unwrap();
+        test_list.quick_add("buy_milk !critical #errands").unwrap();
+        test_list.quick_add("urgent_call !critical #phone").unwrap();
+        test_list.close_list_item("urgent_call", false).unwrap();
+        test_list.create_item("untagged", "No tag", "Critical", None, false).unwrap();
+
+        let next_actions = test_list.next_actions_by_tag();
+
+        assert_eq!(next_actions.len(), 3);
+        assert_eq!(next_actions["work"].get_name(), "deploy");
+        assert_eq!(next_actions["phone"].get_name(), "call_client");
+        assert_eq!(next_actions["errands"].get_name(), "buy_milk");
+    }
+
+    #[test]
+    fn repeated_invalid_input_stops_after_limit() {
+        let mut reads = 0;
+        let result: Option<u32> = read_with_retries(|| { reads += 1; "abc".to_string() }, |input| input.parse().map_err(|_| "Please enter a number".to_string()), 3);
+
+        assert_eq!(result, None);
+        assert_eq!(reads, 3);
+    }
+
+    #[test]
+    fn valid_input_is_accepted_within_limit() {
+        let mut inputs = vec!["", "x", "7"].into_iter();
+        let result: Option<u32> = read_with_retries(|| inputs.next().unwrap_or_default().to_string(), |input| input.parse().map_err(|_| "Please enter a number".to_string()), 3);
+
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn similar_items_are_flagged() {
+        let mut test_list = ToDoList::new("Test", "List used to test duplicate detection");
+        test_list.create_item("Quarterly report", "Write the quarterly finance report", "High", None, false).unwrap();
+        test_list.create_item("Groceries", "Buy milk and bread", "Low", None, false).unwrap();
+        test_list.create_item("Dentist", "Book an appointment", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.find_similar("quarterly  REPORT", ""), vec!["Quarterly report"]);
+        assert_eq!(test_list.find_similar("Finance report", "Write the quarterly finance report for the board"), vec!["Quarterly report"]);
+        assert_eq!(test_list.find_similar("Shopping", "buy milk and  bread"), vec!["Groceries"]);
+        assert!(test_list.find_similar("Call mom", "Ask about the weekend").is_empty());
+    }
+
+    #[test]
+    fn items_are_partitioned_by_status() {
+        let mut test_list = ToDoList::new("Test", "List used to test the partition");
+        for name in ["d", "b", "c", "a", "e"] {
+            test_list.create_item(name, "Item", "Low", None, false).unwrap();
+        }
+        test_list.close_list_item("d", false).unwrap();
+        test_list.close_list_item("a", false).unwrap();
+
+        let (open_items, completed_items) = test_list.partition_by_status();
+        let open_names: Vec<&String> = open_items.into_iter().map(|item| item.0).collect();
+        let completed_names: Vec<&String> = completed_items.into_iter().map(|item| item.0).collect();
+
+        assert_eq!(open_names, vec!["b", "c", "e"]);
+        assert_eq!(completed_names, vec!["a", "d"]);
+    }
+
+    #[test]
+    fn priority_is_stored_as_lowercase_string() {
+        assert_eq!(serde_json::to_string(&Priority::Critical).unwrap(), "\"critical\"");
+        assert_eq!(serde_json::to_string(&Priority::Invalid).unwrap(), "\"invalid\"");
+
+        let priorities: Vec<Priority> = serde_json::from_str(r#"["low", "MEDIUM", "High", "cRiTiCaL", "urgent"]"#).unwrap();
+        assert_eq!(priorities, vec![Priority::Low, Priority::Medium, Priority::High, Priority::Critical, Priority::Invalid]);
+
+        let mut test_list = ToDoList::new("Test", "List used to test priority serialization");
+        test_list.create_item("report", "Write the report", "High", None, false).unwrap();
+        let json = serde_json::to_string(&test_list).unwrap();
+        assert!(json.contains(r#""priority":"high""#));
+        let loaded_list: ToDoList = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded_list.get_item_ref("report").unwrap().get_priority(), &Priority::High);
+    }
+
+    #[test]
+    fn clearing_items_keeps_metadata() {
+        let mut test_list = ToDoList::new("Test", "List used to test clearing");
+        test_list.set_default_priority("High");
+        test_list.create_item("first", "Item", "Low", None, false).unwrap();
+        test_list.create_item("second", "Item", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.clear_all_items(), 2);
+
+        assert_eq!(test_list.stats().total, 0);
+        assert_eq!(test_list.get_name(), "Test");
+        assert_eq!(test_list.get_description(), "List used to test clearing");
+        assert_eq!(test_list.get_default_priority(), &Priority::High);
+        assert_eq!(test_list.clear_all_items(), 0);
+    }
+
+    #[test]
+    fn items_are_filtered_by_label() {
+        let mut test_list = ToDoList::new("Test", "List used to test color labels");
+        for name in ["first", "second", "third"] {
+            test_list.create_item(name, "Item", "Low", None, false).unwrap();
+        }
+        test_list.update_item_label("first", Some("Red")).unwrap();
+        test_list.update_item_label("second", Some("blue")).unwrap();
+        test_list.update_item_label("third", Some("red")).unwrap();
+        test_list.update_item_label("third", None).unwrap();
+
+        let red_items = test_list.filter_by_label("RED");
+
+        assert_eq!(red_items.len(), 1);
+        assert!(red_items.contains_key("first"));
+        assert_eq!(test_list.get_item_ref("first").unwrap().get_label(), &Some("red".to_string()));
+        assert!(test_list.get_item_ref("first").unwrap().to_string().contains("\tLabel: red"));
+        assert!(test_list.update_item_label("missing", Some("red")).is_err());
+    }
+
+    #[test]
+    fn file_in_place_of_lists_directory_is_reported() {
+        let path = std::env::temp_dir().join(format!("to_do_list_not_a_dir_{}", std::process::id()));
+        std::fs::write(&path, "not a directory").unwrap();
+
+        assert!(check_directory(&path).unwrap_err().contains("exists but is not a directory"));
+        assert!(summarize_files(&path).is_empty());
+        let error = ToDoList::new("Test", "List").save_to_storage(&FileStorage::new(&path)).unwrap_err();
+        assert!(error.contains("exists but is not a directory"));
+        std::fs::remove_file(&path).unwrap();
+        assert!(check_directory(&path).unwrap_err().contains("does not exist"));
+    }
+
+    #[test]
+    fn completed_items_are_sorted_last() {
+        let mut test_list = ToDoList::new("Test", "List used to test sinking completed items");
+        test_list.create_item("a_done", "Completed", "Critical", None, false).unwrap();
+        test_list.create_item("b_open", "Open", "Low", None, false).unwrap();
+        test_list.create_item("c_done", "Completed", "Low", None, false).unwrap();
+        test_list.create_item("d_open", "Open", "High", None, false).unwrap();
+        test_list.close_list_item("a_done", false).unwrap();
+        test_list.close_list_item("c_done", false).unwrap();
+        test_list.set_sort_order(SortOrder::Priority);
+
+        assert!(test_list.toggle_completed_last());
+        let names: Vec<&String> = test_list.sort_items().into_iter().map(|item| item.0).collect();
+
+        assert_eq!(names, vec!["d_open", "b_open", "a_done", "c_done"]);
+    }
+
+    #[test]
+    fn count_items_matching_predicate() {
+        let mut test_list = ToDoList::new("Test", "List used to test counting with a predicate");
+        test_list.create_item("first", "Open and high", "High", None, false).unwrap();
+        test_list.create_item("second", "Closed and high", "High", None, false).unwrap();
+        test_list.create_item("third", "Open and low", "Low", None, false).unwrap();
+        test_list.close_list_item("second", false).unwrap();
+
+        let count = test_list.count_where(|item| *item.get_priority() == Priority::High && !item.is_completed());
+
+        assert_eq!(count, 1);
+        assert_eq!(test_list.count_where(|_| true), 3);
+    }
+
+    #[test]
+    fn only_overdue_items_are_exported() {
+        let mut test_list = ToDoList::new("Test", "List used to test the overdue export");
+        test_list.create_item("overdue", "Overdue Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("completed", "Completed Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("upcoming", "Upcoming Item", "Low", Some((2999, 1, 1)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+        test_list.close_list_item("completed", false).unwrap();
+        let path = std::env::temp_dir().join(format!("to_do_list_overdue_{}.json", std::process::id()));
+
+        test_list.export_overdue(&path).unwrap();
+        let exported: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let names: Vec<&String> = exported.as_object().unwrap().keys().collect();
+        assert_eq!(names, vec!["overdue"]);
+    }
+
+    #[test]
+    fn list_is_saved_only_after_interrupt() {
+        let storage = MemoryStorage::new();
+        let flag = AtomicBool::new(false);
+        let test_list = ToDoList::new("interrupted", "List used to test interrupts");
+
+        assert_eq!(save_if_interrupted_in(&test_list, &storage, &flag, || panic!("Asked without an interrupt")), Ok(false));
+        assert!(storage.list().is_empty());
+
+        flag.store(true, Ordering::SeqCst);
+        assert_eq!(save_if_interrupted_in(&test_list, &storage, &flag, || false), Ok(false));
+        assert!(storage.list().is_empty());
+        assert_eq!(save_if_interrupted_in(&test_list, &storage, &flag, || true), Ok(true));
+        assert_eq!(storage.list(), vec!["interrupted".to_string()]);
+        assert!(flag.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn workload_is_counted_per_assignee() {
+        let mut test_list = ToDoList::new("Test", "List used to test the workload per assignee");
+        test_list.create_item("first", "Open Item", "Low", None, false).unwrap();
+        test_list.create_item("second", "Open Item", "Low", None, false).unwrap();
+        test_list.create_item("third", "Completed Item", "Low", None, false).unwrap();
+        test_list.create_item("fourth", "Open Item", "Low", None, false).unwrap();
+        test_list.create_item("fifth", "Unassigned Item", "Low", None, false).unwrap();
+        test_list.update_item_assignee("first", Some("Alex")).unwrap();
+        test_list.update_item_assignee("second", Some(" Alex ")).unwrap();
+        test_list.update_item_assignee("third", Some("Alex")).unwrap();
+        test_list.update_item_assignee("fourth", Some("Sam")).unwrap();
+        test_list.close_list_item("third", false).unwrap();
+
+        let workload = test_list.workload_by_assignee();
+
+        assert_eq!(workload.len(), 3);
+        assert_eq!(workload["Alex"], 2);
+        assert_eq!(workload["Sam"], 1);
+        assert_eq!(workload[UNASSIGNED], 1);
+        assert!(test_list.update_item_assignee("missing", Some("Alex")).is_err());
+    }
+
+    #[test]
+    fn dates_are_humanized_relative_to_today() {
+        let today = Local::now().date_naive();
+
+        assert_eq!(humanize_date(today), "today");
+        assert_eq!(humanize_date(today + Duration::days(1)), "tomorrow");
+        assert_eq!(humanize_date(today - Duration::days(1)), "yesterday");
+        assert_eq!(humanize_date(today + Duration::days(3)), "in 3 days");
+        assert_eq!(humanize_date(today - Duration::days(5)), "5 days ago");
+    }
+
+    #[test]
+    fn relative_due_dates_follow_the_list_setting() {
+        let due_date = Local::now().date_naive() + Duration::days(3);
+        let mut test_list = ToDoList::new("Test", "List used to test relative due dates");
+        test_list.create_item("task", "Item due in three days", "Low", Some((due_date.year(), due_date.month(), due_date.day())), false).unwrap();
+
+        let item = test_list.get_item_ref("task").unwrap();
+
+        assert!(item.to_string().contains(&format!("Due Date:{}\t", due_date)));
+        assert_eq!(test_list.format_item(item), item.to_string());
+        assert!(test_list.toggle_relative_due_dates());
+        let item = test_list.get_item_ref("task").unwrap();
+        assert!(test_list.format_item(item).contains(&format!("Due Date:in 3 days ({})", due_date)));
+    }
+
+    #[test]
+    fn item_names_are_sorted() {
+        let mut test_list = ToDoList::new("Test", "List used to test sorted item names");
+        test_list.create_item("delta", "Item", "Low", None, false).unwrap();
+        test_list.create_item("Bravo", "Item", "Low", None, false).unwrap();
+        test_list.create_item("alpha", "Item", "Low", None, false).unwrap();
+        test_list.create_item("charlie", "Item", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.item_names(), vec!["alpha", "Bravo", "charlie", "delta"]);
+        assert!(ToDoList::new("Empty", "List without items").item_names().is_empty());
+    }
+
+    #[test]
+    fn confirm_honors_assume_yes() {
+        assert!(confirm_with("Confirm?", true, || panic!("No input is read when confirmations are assumed")));
+        assert!(confirm_with("Confirm?", false, || "y".to_string()));
+        assert!(!confirm_with("Confirm?", false, || "n".to_string()));
+        assert!(!confirm_with("Confirm?", false, String::new));
+    }
+
+    #[test]
+    fn items_without_description_are_found() {
+        let mut test_list = ToDoList::new("Test", "List used to test missing descriptions");
+        test_list.create_item("documented", "Item with a description", "Low", None, false).unwrap();
+        test_list.create_item("blank", "   ", "Low", None, false).unwrap();
+        test_list.create_item("another", "Another documented item", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.items_missing_description(), vec!["blank"]);
+    }
+
+    #[test]
+    fn invalid_priorities_are_reset() {
+        let mut test_list = ToDoList::new("Test", "List used to test resetting invalid priorities");
+        test_list.create_item("first", "Imported priority", "urgent!!", None, false).unwrap();
+        test_list.create_item("second", "Imported priority", "p1", None, false).unwrap();
+        test_list.create_item("valid", "Valid priority", "High", None, false).unwrap();
+
+        assert_eq!(test_list.reset_invalid_to(Priority::Low), 2);
+        assert_eq!(test_list.get_item_ref("first").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("second").unwrap().get_priority(), &Priority::Low);
+        assert_eq!(test_list.get_item_ref("valid").unwrap().get_priority(), &Priority::High);
+        assert_eq!(test_list.reset_invalid_to(Priority::Medium), 0);
+    }
+
+    #[test]
+    fn items_are_searched_with_all_keywords() {
+        let mut test_list = ToDoList::new("Test", "List used to test keyword searches");
+        test_list.create_item("groceries", "Buy milk and bread", "Low", None, false).unwrap();
+        test_list.create_item("bakery", "Pick up BREAD", "Low", None, false).unwrap();
+        test_list.create_item("Milk run", "Drive to the farm", "Low", None, false).unwrap();
+        test_list.create_item("laundry", "Wash clothes", "Low", None, false).unwrap();
+
+        let found_items = test_list.search_items_advanced("Milk bread", SearchMode::All);
+
+        assert_eq!(found_items.len(), 1);
+        assert!(found_items.contains_key("groceries"));
+        assert!(test_list.search_items_advanced("   ", SearchMode::All).is_empty());
+    }
+
+    #[test]
+    fn items_are_searched_with_any_keyword() {
+        let mut test_list = ToDoList::new("Test", "List used to test keyword searches");
+        test_list.create_item("groceries", "Buy milk and bread", "Low", None, false).unwrap();
+        test_list.create_item("bakery", "Pick up BREAD", "Low", None, false).unwrap();
+        test_list.create_item("Milk run", "Drive to the farm", "Low", None, false).unwrap();
+        test_list.create_item("laundry", "Wash clothes", "Low", None, false).unwrap();
+
+        let found_items = test_list.search_items_advanced("milk BREAD", SearchMode::Any);
+
+        assert_eq!(found_items.len(), 3);
+        assert!(!found_items.contains_key("laundry"));
+    }
+
+    #[test]
+    fn lists_are_shown_with_open_counts() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_badges_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut groceries = ToDoList::new("groceries", "List used to test open item counts");
+        groceries.create_item("milk", "Buy milk", "Low", None, false).unwrap();
+        groceries.create_item("bread", "Buy bread", "Low", None, false).unwrap();
+        groceries.create_item("eggs", "Buy eggs", "Low", None, false).unwrap();
+        groceries.close_list_item("eggs", false).unwrap();
+        std::fs::write(dir.join("groceries.json"), serde_json::to_string(&groceries).unwrap()).unwrap();
+        std::fs::write(dir.join("broken.json"), "not a list").unwrap();
+
+        let overview = format_lists_with_counts(&dir, false);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(overview, "\t- broken.json (?)\n\t- groceries.json (2 open)\n");
+    }
+
+    #[test]
+    fn due_date_is_deferred_to_next_month() {
+        let mut test_list = ToDoList::new("Test", "List used to test deferring items");
+        test_list.create_item("mid_month", "Item due in May", "Low", Some((2024, 5, 17)), false).unwrap();
+        test_list.create_item("december", "Item due in December", "Low", Some((2024, 12, 31)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+
+        test_list.defer_item_to_next_month("mid_month").unwrap();
+        test_list.defer_item_to_next_month("december").unwrap();
+        test_list.defer_item_to_next_month("undated").unwrap();
+
+        let next_month = Local::now().date_naive().with_day(1).unwrap().checked_add_months(chrono::Months::new(1)).unwrap();
+        assert_eq!(test_list.get_item_ref("mid_month").unwrap().due_date_string(), "2024-06-01");
+        assert_eq!(test_list.get_item_ref("december").unwrap().due_date_string(), "2025-01-01");
+        assert_eq!(test_list.get_item_ref("undated").unwrap().get_due_date(), &Some(next_month));
+        assert!(test_list.defer_item_to_next_month("missing").is_err());
+    }
+
+    #[test]
+    fn read_only_view_exposes_list_data() {
+        let mut test_list = ToDoList::new("Test", "List used to test the read-only view");
+        test_list.create_item("beta", "Open Item", "Low", None, false).unwrap();
+        test_list.create_item("alpha", "Completed Item", "Low", None, false).unwrap();
+        test_list.close_list_item("alpha", false).unwrap();
+
+        let view = test_list.view();
+        let names: Vec<&String> = view.items().into_iter().map(|item| item.0).collect();
+
+        assert_eq!(view.name(), "Test");
+        assert_eq!(view.description(), "List used to test the read-only view");
+        assert_eq!(names, vec!["alpha", "beta"]);
+        assert_eq!(view.stats(), ListStats { total: 2, open: 1, completed: 1, overdue: 0, due_this_week: 0 });
+    }
+
+    #[test]
+    fn two_lists_are_compared() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_compare_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut work = ToDoList::new("work", "First list used to test comparisons");
+        work.create_item("report", "Write the report", "Low", None, false).unwrap();
+        work.create_item("meeting", "Prepare the meeting", "Low", None, false).unwrap();
+        work.create_item("email", "Answer emails", "Low", None, false).unwrap();
+        let mut copy = ToDoList::new("copy", "Second list used to test comparisons");
+        copy.create_item("report", "Write the report", "Low", None, false).unwrap();
+        copy.create_item("meeting", "Prepare the meeting", "High", None, false).unwrap();
+        copy.create_item("invoice", "Send the invoice", "Low", None, false).unwrap();
+        std::fs::write(dir.join("work.json"), serde_json::to_string(&work).unwrap()).unwrap();
+        std::fs::write(dir.join("copy.json"), serde_json::to_string(&copy).unwrap()).unwrap();
+
+        let report = compare_lists_in(&dir, "work", "copy.json");
+        let same = compare_lists_in(&dir, "work", "work");
+        let missing = compare_lists_in(&dir, "work", "missing");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.unwrap(), "Only in work: email\nOnly in copy: invoice\nDifferent in both: meeting");
+        assert_eq!(same.unwrap(), "The lists work and work contain the same items");
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn changes_are_saved_automatically_when_enabled() {
+        let storage = MemoryStorage::new();
+        let mut test_list = ToDoList::new("auto", "List used to test auto-save");
+        test_list.create_item("task", "Original description", "Low", None, false).unwrap();
+
+        test_list.update_item_description("task", "Unsaved description").unwrap();
+        assert!(!auto_save_in(&test_list, &storage).unwrap());
+        assert!(storage.list().is_empty());
+
+        assert!(test_list.toggle_auto_save());
+        test_list.update_item_description("task", "Saved description").unwrap();
+        assert!(auto_save_in(&test_list, &storage).unwrap());
+
+        let loaded_list = ToDoList::load_from_storage(&storage, "auto").unwrap();
+        assert_eq!(loaded_list.get_item_ref("task").unwrap().get_description(), "Saved description");
+        assert!(loaded_list.is_auto_save());
+    }
+
+    #[test]
+    fn most_overdue_items_come_first() {
+        let mut test_list = ToDoList::new("Test", "List used to test the most overdue items");
+        test_list.create_item("recent", "Overdue Item", "Low", Some((2020, 6, 1)), false).unwrap();
+        test_list.create_item("oldest", "Overdue Item", "Low", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("middle", "Overdue Item", "Low", Some((2010, 3, 15)), false).unwrap();
+        test_list.create_item("completed", "Completed Item", "Low", Some((2000, 1, 1)), false).unwrap();
+        test_list.create_item("upcoming", "Upcoming Item", "Low", Some((2999, 1, 1)), false).unwrap();
+        test_list.close_list_item("completed", false).unwrap();
+
+        let top_two: Vec<&str> = test_list.top_overdue(2).iter().map(|item| item.0.as_str()).collect();
+        let all_overdue: Vec<&str> = test_list.top_overdue(10).iter().map(|item| item.0.as_str()).collect();
+
+        assert_eq!(top_two, vec!["oldest", "middle"]);
+        assert_eq!(all_overdue, vec!["oldest", "middle", "recent"]);
+    }
+
+    #[test]
+    fn list_survives_json_round_trip() {
+        let mut test_list = ToDoList::new("Test", "List used to test the JSON round trip");
+        test_list.create_item("report", "Write the report", "High", Some((2024, 5, 1)), false).unwrap();
+        test_list.create_item("review", "Review the report", "Low", None, false).unwrap();
+        test_list.add_item_dependency("review", "report").unwrap();
+        test_list.add_item_comment("report", "First draft done").unwrap();
+        test_list.update_item_label("report", Some("red")).unwrap();
+        test_list.update_item_assignee("review", Some("Alex")).unwrap();
+        test_list.close_list_item("report", false).unwrap();
+        test_list.set_max_items(Some(10));
+        test_list.set_sort_order(SortOrder::DueDate);
+        test_list.toggle_completed_last();
+        test_list.toggle_auto_save();
+        test_list.toggle_normalize_on_load();
+        test_list.toggle_reject_due_dates_before_creation();
+        test_list.toggle_relative_due_dates();
+
+        assert_round_trip(&test_list);
+        assert_round_trip(&ToDoList::new("Empty", "List without items"));
+    }
+
+    #[test]
+    fn completion_date_is_projected() {
+        let today = Local::now().date_naive();
+        let mut test_list = ToDoList::new("Test", "List used to test the completion forecast");
+        for name in ["first", "second", "third", "fourth", "fifth"] {
+            test_list.create_item(name, "Open Item", "Low", None, false).unwrap();
+        }
+
+        assert_eq!(test_list.projected_completion(0.0), None);
+        assert_eq!(test_list.projected_completion(-1.0), None);
+        assert_eq!(test_list.projected_completion(1.0), Some(today + Duration::days(5)));
+        assert_eq!(test_list.projected_completion(2.0), Some(today + Duration::days(3)));
+        assert_eq!(test_list.projected_completion(0.5), Some(today + Duration::days(10)));
+        assert_eq!(test_list.projected_completion(1e-300), None);
+    }
+
+    #[test]
+    fn completed_list_has_no_projection() {
+        let mut test_list = ToDoList::new("Test", "List used to test the completion forecast");
+        test_list.create_item("done", "Completed Item", "Low", None, false).unwrap();
+        test_list.close_list_item("done", false).unwrap();
+
+        assert_eq!(test_list.projected_completion(1.0), None);
+        assert_eq!(ToDoList::new("Empty", "List without items").projected_completion(1.0), None);
+    }
+
+    #[test]
+    fn lists_are_filtered_by_category() {
+        let dir = std::env::temp_dir().join(format!("to_do_list_categories_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut work = ToDoList::new("work", "Categorized list");
+        assert!(work.add_category("Office"));
+        assert!(!work.add_category("office "));
+        let mut home = ToDoList::new("home", "List with another category");
+        home.add_category("private");
+        let mut moved = ToDoList::new("moved", "List whose category was removed");
+        moved.add_category("office");
+        assert!(moved.remove_category("OFFICE"));
+        let plain = ToDoList::new("plain", "List without categories");
+        for list in [&work, &home, &moved, &plain] {
+            std::fs::write(dir.join(format!("{}.json", list.get_name())), serde_json::to_string(list).unwrap()).unwrap();
+        }
+
+        let office_lists = lists_with_category_in(&dir, "office");
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(office_lists, vec!["work".to_string()]);
+        assert_eq!(work.get_categories(), ["office".to_string()]);
+    }
+
+    #[test]
+    fn weekend_due_dates_are_detected() {
+        let mut test_list = ToDoList::new("Test", "List used to test weekend due dates");
+        test_list.create_item("saturday", "Due on a Saturday", "Low", Some((2024, 5, 4)), false).unwrap();
+        test_list.create_item("sunday", "Due on a Sunday", "Low", Some((2024, 5, 5)), false).unwrap();
+        test_list.create_item("monday", "Due on a Monday", "Low", Some((2024, 5, 6)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+
+        assert!(test_list.get_item_ref("saturday").unwrap().is_due_on_weekend());
+        assert!(test_list.get_item_ref("sunday").unwrap().is_due_on_weekend());
+        assert!(!test_list.get_item_ref("monday").unwrap().is_due_on_weekend());
+        assert!(!test_list.get_item_ref("undated").unwrap().is_due_on_weekend());
+
+        let weekend_items = test_list.items_due_on_weekend();
+        assert_eq!(weekend_items.len(), 2);
+        assert!(weekend_items.contains_key("saturday"));
+        assert!(weekend_items.contains_key("sunday"));
+    }
+
+    #[test]
+    fn items_are_renamed_by_prefix() {
+        let mut test_list = ToDoList::new("Test", "List used to test renaming by prefix");
+        test_list.create_item("old:report", "Prefixed Item", "Low", None, false).unwrap();
+        test_list.create_item("old:review", "Prefixed Item", "Low", None, false).unwrap();
+        test_list.create_item("old:taken", "Prefixed Item with a colliding new name", "Low", None, false).unwrap();
+        test_list.create_item("new:taken", "Existing Item", "Low", None, false).unwrap();
+        test_list.create_item("other", "Item without the prefix", "Low", None, false).unwrap();
+        test_list.add_item_dependency("old:review", "old:report").unwrap();
+
+        assert_eq!(test_list.rename_items_with_prefix("", "new:"), 0);
+        assert_eq!(test_list.rename_items_with_prefix("old:", "new:"), 2);
+        assert_eq!(test_list.item_names(), vec!["new:report", "new:review", "new:taken", "old:taken", "other"]);
+        assert_eq!(test_list.get_item_ref("new:report").unwrap().get_name(), "new:report");
+        assert_eq!(test_list.get_item_ref("new:taken").unwrap().get_description(), "Existing Item");
+        assert_eq!(test_list.get_item_ref("new:review").unwrap().get_dependencies(), ["new:report".to_string()]);
+    }
+
+    #[test]
+    fn items_below_priority_threshold_are_excluded() {
+        let mut test_list = ToDoList::new("Test", "List used to test the priority threshold");
+        test_list.create_item("low", "Low Item", "Low", None, false).unwrap();
+        test_list.create_item("medium_b", "Medium Item", "Medium", None, false).unwrap();
+        test_list.create_item("medium_a", "Medium Item", "Medium", None, false).unwrap();
+        test_list.create_item("critical", "Critical Item", "Critical", None, false).unwrap();
+        test_list.create_item("high_done", "Completed Item", "High", None, false).unwrap();
+        test_list.close_list_item("high_done", false).unwrap();
+
+        let names: Vec<&str> = test_list.items_at_least(&Priority::Medium).iter().map(|item| item.0.as_str()).collect();
+
+        assert_eq!(names, vec!["critical", "medium_a", "medium_b"]);
+    }
+
+    #[test]
+    fn list_is_exported_as_html() {
+        let mut test_list = ToDoList::new("Chores & <Errands>", "List used to test the \"HTML\" export");
+        test_list.create_item("late", "Overdue Item", "High", Some((2001, 1, 1)), false).unwrap();
+        test_list.create_item("<b>done</b>", "Completed Item", "Low", None, false).unwrap();
+        test_list.close_list_item("<b>done</b>", false).unwrap();
+
+        let html = test_list.export_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains("<h1>Chores &amp; &lt;Errands&gt;</h1>"));
+        assert!(html.contains("<h2>List used to test the &quot;HTML&quot; export</h2>"));
+        assert!(html.contains("<tr class=\"overdue\"><td>late</td><td>High</td><td>2001-01-01</td><td>&#9744; Open</td></tr>"));
+        assert!(html.contains("<tr><td>&lt;b&gt;done&lt;/b&gt;</td><td>Low</td><td>(none)</td><td>&#9745; Completed</td></tr>"));
+        assert!(!html.contains("<b>done</b>"));
+    }
+
+    #[test]
+    fn only_matching_items_are_tagged() {
+        let mut test_list = ToDoList::new("Test", "List used to test tagging by search");
+        test_list.create_item("groceries", "Buy milk and bread", "Low", None, false).unwrap();
+        test_list.create_item("Milk run", "Drive to the farm", "Low", None, false).unwrap();
+        test_list.create_item("laundry", "Wash clothes", "Low", None, false).unwrap();
+
+        assert_eq!(test_list.tag_matching("MILK", "shopping"), 2);
+        assert_eq!(test_list.tag_matching("milk", "shopping"), 0);
+
+        assert_eq!(test_list.get_item_ref("groceries").unwrap().get_tags(), ["shopping".to_string()]);
+        assert_eq!(test_list.get_item_ref("Milk run").unwrap().get_tags(), ["shopping".to_string()]);
+        assert!(test_list.get_item_ref("laundry").unwrap().get_tags().is_empty());
+    }
+
+    #[test]
+    fn starting_an_item_sets_the_started_date() {
+        let mut test_list = ToDoList::new("Test", "List used to test started items");
+        test_list.create_item("started", "Started Item", "Low", None, false).unwrap();
+        test_list.create_item("finished", "Started and completed Item", "Low", None, false).unwrap();
+        test_list.create_item("waiting", "Item that was not started", "Low", None, false).unwrap();
+        assert_eq!(test_list.get_item_ref("started").unwrap().get_started_date(), &None);
+
+        test_list.start_item("started").unwrap();
+        test_list.start_item("finished").unwrap();
+        test_list.close_list_item("finished", false).unwrap();
+
+        assert_eq!(test_list.get_item_ref("started").unwrap().get_started_date(), &Some(Local::now().date_naive()));
+        let in_progress = test_list.in_progress_items();
+        assert_eq!(in_progress.len(), 1);
+        assert!(in_progress.contains_key("started"));
+        assert!(test_list.start_item("missing").is_err());
+    }
+
+    #[test]
+    fn items_are_grouped_by_due_week() {
+        let mut test_list = ToDoList::new("Test", "List used to test grouping by due week");
+        test_list.create_item("new_year", "Due on a Thursday in ISO week 1 of 2026", "Low", Some((2026, 1, 1)), false).unwrap();
+        test_list.create_item("before_new_year", "Due on a Monday in ISO week 1 of 2026", "Low", Some((2025, 12, 29)), false).unwrap();
+        test_list.create_item("christmas", "Due in ISO week 52 of 2025", "Low", Some((2025, 12, 25)), false).unwrap();
+        test_list.create_item("late_january", "Due in ISO week 5 of 2026", "Low", Some((2026, 1, 28)), false).unwrap();
+        test_list.create_item("undated", "Item without due date", "Low", None, false).unwrap();
+
+        let weeks = test_list.group_by_due_week();
+        let names = |key: (i32, u32)| -> Vec<&str> { weeks[&key].iter().map(|item| item.get_name()).collect() };
+
+        assert_eq!(weeks.keys().copied().collect::<Vec<_>>(), vec![(2025, 52), (2026, 1), (2026, 5)]);
+        assert_eq!(names((2025, 52)), vec!["christmas"]);
+        assert_eq!(names((2026, 1)), vec!["before_new_year", "new_year"]);
+        assert_eq!(names((2026, 5)), vec!["late_january"]);
+        assert!(test_list.format_grouped_by_due_week().starts_with("Week 52 of 2025\n"));
+    }
 }
\ No newline at end of file
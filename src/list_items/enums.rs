@@ -3,12 +3,15 @@
 //! The modules defines enums  used in ToDoLists or Items. Enums may include 
 //! errors such as `ToDoSelectionError` or attributs like `Priority`
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 
 /// The `Priority` enum is used to store the priority assigned to an Item in the ToDoList.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Priorities are ordered from Invalid (lowest) over Low, Medium, and High to Critical (highest).
+/// In JSON, a Priority is stored as a lowercase string and read case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Priority {
     /// Indicates low priority task
     Low,
@@ -16,13 +19,15 @@ pub enum Priority {
     Medium,
     /// Indicates high priority task
     High,
+    /// Indicates critical priority task
+    Critical,
     /// Is assigned when an invalid value was submitted to initialize the Priority struct
     Invalid,
 }
 
 impl Priority {
     /// Derives a new Priority instance from a &str input value. 
-    /// Permissable values are "low", "medium", or "high". For any other value, the `invalid` Priority is assigned.
+    /// Permissable values are "low", "medium", "high", or "critical". For any other value, the `invalid` Priority is assigned.
     /// 
     /// # Arguments
     /// * item_name : &str - Desired Priority variant
@@ -36,10 +41,94 @@ impl Priority {
             Self::Medium
         } else if input.to_lowercase().eq("high") {
             Self::High
+        } else if input.to_lowercase().eq("critical") {
+            Self::Critical
         } else {
             Self::Invalid
         }
     }
+
+    /// Returns the next higher Priority (Low -> Medium -> High).
+    /// Escalation stops at High since Critical has to be assigned deliberately.
+    /// High, Critical, and Invalid are returned unchanged.
+    /// 
+    /// # Returns
+    /// * `Priority`: The escalated Priority
+    pub fn escalate(&self) -> Self {
+        use Priority::*;
+        match self {
+            Low => Medium,
+            Medium => High,
+            High => High,
+            Critical => Critical,
+            Invalid => Invalid,
+        }
+    }
+
+    /// Returns the rank of the Priority that is used to order Priorities.
+    /// 
+    /// # Returns
+    /// * `u8`: Rank of the Priority, higher values indicate a higher Priority
+    fn rank(&self) -> u8 {
+        use Priority::*;
+        match self {
+            Invalid => 0,
+            Low => 1,
+            Medium => 2,
+            High => 3,
+            Critical => 4,
+        }
+    }
+
+    /// Returns a symbol that represents the Priority without relying on color.
+    /// The symbols are meant to keep the priority recognizable on monochrome terminals.
+    /// 
+    /// # Returns
+    /// * `&'static str`: Symbol assigned to the Priority variant
+    pub fn symbol(&self) -> &'static str {
+        use Priority::*;
+        match self {
+            Low => "!",
+            Medium => "!!",
+            High => "!!!",
+            Critical => "!!!!",
+            Invalid => "?",
+        }
+    }
+}
+
+impl Serialize for Priority {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use Priority::*;
+        let value = match self {
+            Low => "low",
+            Medium => "medium",
+            High => "high",
+            Critical => "critical",
+            Invalid => "invalid",
+        };
+        serializer.serialize_str(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for Priority {
+    // Unknown values become the Invalid priority, matching `Priority::from_str`
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(Priority::from_str(&value))
+    }
+}
+
+impl PartialOrd for Priority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Priority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
 }
 
 impl Display for Priority {
@@ -55,6 +144,9 @@ impl Display for Priority {
             High => write!(
                 f, "High"
             ),           
+            Critical => write!(
+                f, "Critical"
+            ),
             Invalid => write!(
                 f, "Unknown"
             ),                    
@@ -62,12 +154,52 @@ impl Display for Priority {
     }
 }
 
+/// The `SortOrder` enum stores how the Items of a ToDoList are sorted when all Items are displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// Sorts Items alphabetically by name
+    #[default]
+    Alphabetical,
+    /// Sorts Items by descending priority
+    Priority,
+    /// Sorts Items by ascending due date, Items without a due date last
+    DueDate,
+    /// Sorts Items by creation date, starting with the oldest Item
+    Age,
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        use SortOrder::*;
+        match self {
+            Alphabetical => write!(f, "Alphabetical"),
+            Priority => write!(f, "Priority"),
+            DueDate => write!(f, "Due Date"),
+            Age => write!(f, "Age"),
+        }
+    }
+}
+
+/// The `SearchMode` enum stores how multiple keywords of a search are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Items must contain every keyword
+    All,
+    /// Items must contain at least one keyword
+    Any,
+}
+
 /// Enum to handle errors caused by the invalid selection of ToDOList Items.
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum ToDoSelectionError {
     ToDoNotFound,
     ToDoAlreadyPresent,
+    DependenciesIncomplete,
+    InvalidDate,
+    ListArchived,
+    DueDateBeforeCreation,
+    ListFull,
 }
 
 impl Display for ToDoSelectionError {
@@ -82,6 +214,26 @@ impl Display for ToDoSelectionError {
                 f,
                 "The submitted To-Do item already exists."
             ),
+            DependenciesIncomplete => write!(
+                f,
+                "The To-Do item depends on items that are not completed yet."
+            ),
+            InvalidDate => write!(
+                f,
+                "The submitted values do not form a valid date."
+            ),
+            ListArchived => write!(
+                f,
+                "The list is archived and cannot be changed."
+            ),
+            DueDateBeforeCreation => write!(
+                f,
+                "The due date lies before the creation date of the To-Do item."
+            ),
+            ListFull => write!(
+                f,
+                "The list already contains the maximum number of To-Do items."
+            ),
         }
     }
 }
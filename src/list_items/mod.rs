@@ -3,4 +3,4 @@
 //! The modules stores structs related to the creation and use of to-do lists and their individual items.
 
 pub mod structs;
-mod enums;
\ No newline at end of file
+pub mod enums;
\ No newline at end of file
@@ -5,17 +5,24 @@
 //! In general, an Item is used to describe a specific task and attributes like priority
 //! or due date and ToDoList acts as a container that summarizes different Items.
 
-use crate::list_items::enums::{Priority, ToDoSelectionError};
-use crate::utils::functions::{sort_list};
-use std::collections::HashMap;
+use crate::list_items::enums::{Priority, SearchMode, SortOrder, ToDoSelectionError};
+use crate::storage::structs::FileStorage;
+use crate::storage::traits::Storage;
+use crate::utils::functions::{compare_folded, humanize_date, sort_list, sort_list_folded};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::io;
 use std::fs::{write, File};
-use chrono::{Local, NaiveDate};
-use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use chrono::{Datelike, Duration, Local, Months, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize, Serializer};
+
+/// Name under which open Items without an assignee are counted in the workload overview.
+pub const UNASSIGNED: &str = "unassigned";
 
 /// Representation of a single to-do list item.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Item {
     /// Name of the item
     name: String,
@@ -29,6 +36,48 @@ pub struct Item {
     due_date: Option<NaiveDate>,
     /// Flag to mark if an item was completed
     completed: bool,
+    /// Tags used to categorize the item
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Optional name of the parent item
+    #[serde(default)]
+    parent: Option<String>,
+    /// Names of the items that have to be completed before this item
+    #[serde(default)]
+    depends_on: Vec<String>,
+    /// Links to webpages or tickets related to the item
+    #[serde(default)]
+    links: Vec<String>,
+    /// Flag to keep an item above all unpinned items
+    #[serde(default)]
+    pinned: bool,
+    /// Position of the item in the custom order of the list
+    #[serde(default)]
+    order: u32,
+    /// Date when the item was completed
+    #[serde(default)]
+    completion_date: Option<NaiveDate>,
+    /// Name of the list the item was deleted from while it is in the trash
+    #[serde(default)]
+    origin_list: Option<String>,
+    /// Paths of local files related to the item
+    #[serde(default)]
+    attachments: Vec<PathBuf>,
+    /// Optional number of days before the due date at which a reminder is due
+    #[serde(default)]
+    reminder_days_before: Option<u32>,
+    /// Dated comments that document the progress of the item
+    #[serde(default)]
+    comments: Vec<(NaiveDate, String)>,
+    /// Optional color label used for personal categorization
+    #[serde(default)]
+    label: Option<String>,
+    /// Optional name of the person responsible for the item
+    #[serde(default)]
+    assignee: Option<String>,
+    /// Optional date on which the work on the item started
+    #[serde(default)]
+    started_date: Option<NaiveDate>,
 }
 
 impl Item {
@@ -36,7 +85,6 @@ impl Item {
     /// The creation date is always the day when the function was called.
     /// The due_date_ymd parameter is optional and can be used to assign a due date to the Item.
     /// A Some variant is expected to use a Tuple with 3 numeric values presenting year, month, day, in this order.
-    /// If an invalid value is used, the function will ignore it and print a message in the log.
     /// 
     /// # Arguments
     /// * name : &str - Name of the Item
@@ -46,25 +94,37 @@ impl Item {
     /// 
     /// # Returns
     /// * `Item`: A new instance of an Item 
-    fn new(name: &str, description: &str, priority: &str, due_date_ymd: Option<(i32, u32, u32)>) -> Self {
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::InvalidDate`: The submitted values do not form a valid date.
+    fn new(name: &str, description: &str, priority: &str, due_date_ymd: Option<(i32, u32, u32)>) -> Result<Self, ToDoSelectionError> {
         // Process the optional due date parameter
-        let mut due_date: Option<NaiveDate> = None;
-        if let Some(ymd) = due_date_ymd {
-            if let Some(assigned_due_date) = NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2) {
-                due_date = Some(assigned_due_date);
-            } else {
-                println!("The submitted values for year {}, month {}, and day {} did not return a valid date", ymd.0, ymd.1, ymd.2);
-            }
-        }
+        let due_date = due_date_ymd
+            .map(|ymd| NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).ok_or(ToDoSelectionError::InvalidDate))
+            .transpose()?;
 
-        Item { 
+        Ok(Item { 
             name: name.to_string(), 
             description: description.to_string(), 
             priority: Priority::from_str(priority), 
             creation_date: Local::now().date_naive(), 
             due_date, 
-            completed: false 
-        }
+            completed: false,
+            tags: vec![],
+            parent: None,
+            depends_on: vec![],
+            links: vec![],
+            pinned: false,
+            order: 0,
+            completion_date: None,
+            origin_list: None,
+            attachments: vec![],
+            reminder_days_before: None,
+            comments: vec![],
+            label: None,
+            assignee: None,
+            started_date: None,
+        })
     }
     /// Creates a reference to the `Item` name.
     /// 
@@ -106,6 +166,154 @@ impl Item {
         &self.due_date
     }           
 
+    /// Checks whether the `Item` due_date lies before its creation date, which is usually a mistake.
+    /// Depending on `reject`, the method either returns an error or only prints a warning in the log.
+    /// 
+    /// # Arguments
+    /// * reject : bool - Set to true to return an error instead of a warning
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::DueDateBeforeCreation`: The due date lies before the creation date and reject was set to true.
+    pub fn check_due_date(&self, reject: bool) -> Result<(), ToDoSelectionError> {
+        match self.due_date {
+            Some(due_date) if due_date < self.creation_date => {
+                if reject {
+                    return Err(ToDoSelectionError::DueDateBeforeCreation);
+                }
+                println!("Warning: The due date {} of {} lies before its creation date {}", due_date, self.name, self.creation_date);
+                Ok(())
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts the words in the `Item` description. Words are separated by whitespace.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of words in the description
+    pub fn description_word_count(&self) -> usize {
+        self.description.split_whitespace().count()
+    }
+
+    /// Serializes the `Item` on its own, e.g. to share it or to import it into another list.
+    /// 
+    /// # Returns
+    /// * `String`: Pretty-printed JSON representation of the Item
+    /// 
+    /// # Errors
+    /// * Returns the serde_json error if the Item cannot be serialized
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Formats the `Item` due_date for exports. Dates use the `YYYY-MM-DD` format
+    /// and a missing due date is represented by `(none)`.
+    /// 
+    /// # Returns
+    /// * `String`: Formatted due date
+    pub fn due_date_string(&self) -> String {
+        match self.due_date {
+            Some(due_date) => due_date.format("%Y-%m-%d").to_string(),
+            None => "(none)".to_string(),
+        }
+    }
+
+    /// Renders every field of the `Item` as a labeled block with one field per line.
+    /// Unlike the one-line `Display` output, optional fields are always listed.
+    /// 
+    /// # Returns
+    /// * `String`: Labeled block with all fields of the Item
+    pub fn format_details(&self) -> String {
+        let or_none = |values: Vec<String>| if values.is_empty() { "(none)".to_string() } else { values.join(", ") };
+        let missing_attachments = self.missing_attachments();
+        let attachments: Vec<String> = self.attachments.iter().map(|attachment| {
+            if missing_attachments.contains(&attachment) {
+                format!("{} (missing)", attachment.display())
+            } else {
+                attachment.display().to_string()
+            }
+        }).collect();
+        let mut lines = vec![
+            format!("Name: {}", self.name),
+            format!("Description: {} ({} words)", self.description, self.description_word_count()),
+            format!("Priority: {} {}", self.priority.symbol(), self.priority),
+            format!("Creation Date: {} ({}d old)", self.creation_date, self.age_days()),
+            format!("Due Date: {}", self.due_date_string()),
+            format!("Completed: {}", self.completed),
+            format!("Started: {}", self.started_date.map_or("(none)".to_string(), |date| date.to_string())),
+            format!("Completion Date: {}", self.completion_date.map_or("(none)".to_string(), |date| date.to_string())),
+            format!("Tags: {}", or_none(self.tags.clone())),
+            format!("Parent: {}", self.parent.as_deref().unwrap_or("(none)")),
+            format!("Prerequisites: {}", or_none(self.depends_on.clone())),
+            format!("Links: {}", or_none(self.links.clone())),
+            format!("Attachments: {}", or_none(attachments)),
+            format!("Label: {}", self.label.as_deref().unwrap_or("(none)")),
+            format!("Assignee: {}", self.assignee.as_deref().unwrap_or("(none)")),
+            format!("Pinned: {}", self.pinned),
+            format!("Reminder: {}", self.reminder_days_before.map_or("(none)".to_string(), |days| format!("{} day(s) before the due date", days))),
+        ];
+        if let Some(origin_list) = &self.origin_list {
+            lines.push(format!("Deleted from: {}", origin_list));
+        }
+        if self.comments.is_empty() {
+            lines.push("Comments: (none)".to_string());
+        } else {
+            lines.push("Comments:".to_string());
+            for (date, text) in self.comments.iter().rev() {
+                lines.push(format!("\t{}: {}", date, text));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Creates a reference to the `Item` tags.
+    /// 
+    /// # Returns
+    /// * `&[String]`: Item tags
+    pub fn get_tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Creates a reference to the name of the `Item` parent.
+    /// 
+    /// # Returns
+    /// * `&Option<String>`: Name of the parent Item (when applicable)
+    pub fn get_parent(&self) -> &Option<String> {
+        &self.parent
+    }
+
+    /// Creates a reference to the names of the Items this `Item` depends on.
+    /// 
+    /// # Returns
+    /// * `&[String]`: Names of the prerequisite Items
+    pub fn get_dependencies(&self) -> &[String] {
+        &self.depends_on
+    }
+
+    /// Creates a reference to the `Item` links.
+    /// 
+    /// # Returns
+    /// * `&[String]`: Links attached to the Item
+    pub fn get_links(&self) -> &[String] {
+        &self.links
+    }
+
+    /// Creates a reference to the `Item` attachments.
+    /// 
+    /// # Returns
+    /// * `&[PathBuf]`: Paths of the attached files
+    pub fn get_attachments(&self) -> &[PathBuf] {
+        &self.attachments
+    }
+
+    /// Lists all attachments of the `Item` that no longer exist on disk.
+    /// 
+    /// # Returns
+    /// * `Vec<&PathBuf>`: Paths of the missing files
+    pub fn missing_attachments(&self) -> Vec<&PathBuf> {
+        self.attachments.iter().filter(|path| !path.exists()).collect()
+    }
+
     /// Checks whether the Item is overdue (i.e., the due date lies in the past).
     /// 
     /// # Returns
@@ -118,6 +326,126 @@ impl Item {
         }
     }   
 
+    /// Calculates the number of days since the `Item` was created.
+    /// 
+    /// # Returns
+    /// * `i64`: Age of the Item in days
+    pub fn age_days(&self) -> i64 {
+        (Local::now().date_naive() - self.creation_date).num_days()
+    }
+
+    /// Creates a reference to the number of days before the due date at which the reminder is due.
+    /// 
+    /// # Returns
+    /// * `&Option<u32>`: Reminder offset in days (when applicable)
+    pub fn get_reminder_days_before(&self) -> &Option<u32> {
+        &self.reminder_days_before
+    }
+
+    /// Creates a reference to the `Item` color label.
+    /// 
+    /// # Returns
+    /// * `&Option<String>`: Color label of the Item (when applicable)
+    pub fn get_label(&self) -> &Option<String> {
+        &self.label
+    }
+
+    /// Creates a reference to the `Item` assignee.
+    /// 
+    /// # Returns
+    /// * `&Option<String>`: Name of the person responsible for the Item (when applicable)
+    pub fn get_assignee(&self) -> &Option<String> {
+        &self.assignee
+    }
+
+    /// Creates a reference to the `Item` comments in the order they were added.
+    /// 
+    /// # Returns
+    /// * `&[(NaiveDate, String)]`: Comments with the date they were added
+    pub fn get_comments(&self) -> &[(NaiveDate, String)] {
+        &self.comments
+    }
+
+    /// Appends a comment to the `Item` that is stamped with today's date.
+    /// 
+    /// # Arguments
+    /// * text : &str - Text of the comment
+    pub fn add_comment(&mut self, text: &str) {
+        self.comments.push((Local::now().date_naive(), text.to_string()));
+    }
+
+    /// Calculates the date of the reminder by subtracting the reminder offset from the due date.
+    /// 
+    /// # Returns
+    /// * `Option<NaiveDate>`: Reminder date or `None` if the Item has no due date or no reminder
+    pub fn reminder_date(&self) -> Option<NaiveDate> {
+        match (self.due_date, self.reminder_days_before) {
+            (Some(due_date), Some(days_before)) => Some(due_date - Duration::days(days_before as i64)),
+            _ => None,
+        }
+    }
+
+    /// Checks whether the Item is due on the current day.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the due date is today
+    pub fn is_due_today(&self) -> bool {
+        self.due_date == Some(Local::now().date_naive())
+    }
+
+    /// Returns the position of the `Item` in the custom order of the list.
+    /// 
+    /// # Returns
+    /// * `u32`: Item position
+    pub fn get_order(&self) -> u32 {
+        self.order
+    }
+
+    /// Creates a reference to the `Item` started_date.
+    /// 
+    /// # Returns
+    /// * `&Option<NaiveDate>`: Date on which the work on the Item started (when applicable)
+    pub fn get_started_date(&self) -> &Option<NaiveDate> {
+        &self.started_date
+    }
+
+    /// Indicates whether the work on the `Item` started but the Item is not completed yet.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the Item is in progress
+    pub fn is_in_progress(&self) -> bool {
+        self.started_date.is_some() && !self.completed
+    }
+
+    /// Marks the start of the work on the `Item`. The started date is set to the current day.
+    pub fn start(&mut self) {
+        self.started_date = Some(Local::now().date_naive());
+    }
+
+    /// Creates a reference to the `Item` completion_date.
+    /// 
+    /// # Returns
+    /// * `&Option<NaiveDate>`: Item completion date (when applicable)
+    pub fn get_completion_date(&self) -> &Option<NaiveDate> {
+        &self.completion_date
+    }
+
+    /// Creates a reference to the name of the list the `Item` was deleted from.
+    /// 
+    /// # Returns
+    /// * `&Option<String>`: Name of the original list (only set for Items in the trash)
+    pub fn get_origin_list(&self) -> &Option<String> {
+        &self.origin_list
+    }
+
+    /// Indicates whether the item is pinned above unpinned items.
+    /// 
+    /// # Returns
+    /// * `bool`: Is true if the `Item` is pinned
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
     /// Indicates whether the item has been completed yet.
     /// 
     /// # Returns
@@ -143,42 +471,228 @@ impl Item {
         self.priority = Priority::from_str(new_priority);
     }
 
+    /// Raise the `Item` priority by one step. See `Priority::escalate`.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the priority changed
+    fn escalate_priority(&mut self) -> bool {
+        let escalated = self.priority.escalate();
+        let changed = escalated != self.priority;
+        self.priority = escalated;
+        changed
+    }
+
+    /// Indicates whether the `Item` due_date falls on a Saturday or Sunday.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the Item is due on a weekend, `false` if it is due on a weekday or has no due date
+    pub fn is_due_on_weekend(&self) -> bool {
+        self.due_date.is_some_and(|due_date| matches!(due_date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+
+    /// Moves the `Item` due_date to the following Monday if it falls on a Saturday or Sunday.
+    /// Due dates on weekdays and Items without a due date stay unchanged.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the due date changed
+    pub fn move_due_to_next_weekday(&mut self) -> bool {
+        let Some(due_date) = self.due_date else {
+            return false;
+        };
+        let days = match due_date.weekday() {
+            Weekday::Sat => 2,
+            Weekday::Sun => 1,
+            _ => return false,
+        };
+        self.due_date = Some(due_date + Duration::days(days));
+        true
+    }
+
+    /// Moves the `Item` due_date to the first day of the month after the current due date.
+    /// Items without a due date are due on the first day of the month after today.
+    pub fn defer_to_next_month(&mut self) {
+        let base_date = self.due_date.unwrap_or_else(|| Local::now().date_naive());
+        let first_of_month = base_date.with_day(1).expect("Every month has a first day");
+        self.due_date = first_of_month.checked_add_months(Months::new(1));
+    }
+
     /// Change the `Item` due_date.
-    /// If an invalid date is submitted, the method will not update the Item and print a message in the log.
+    /// If an invalid date is submitted, the method will not update the Item and return an error instead.
     /// 
     /// # Arguments
     /// * ymd : (i32, u32, u32) - Updated due_date of the Item (year, month, day)    
-    fn update_due_date(&mut self, ymd: (i32, u32, u32)) {
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::InvalidDate`: The submitted values do not form a valid date.
+    fn update_due_date(&mut self, ymd: (i32, u32, u32)) -> Result<(), ToDoSelectionError> {
         if let Some(due_date) = NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2) {
-            self.due_date = Some(due_date)
+            self.due_date = Some(due_date);
+            Ok(())
         } else {
-            println!("The submitted values for year {}, month {}, and day {} did not return a valid date", ymd.0, ymd.1, ymd.2);
+            Err(ToDoSelectionError::InvalidDate)
+        }
+    }
+
+    /// Assign a tag to the `Item`. Tags that are already assigned are ignored.
+    /// 
+    /// # Arguments
+    /// * tag : `&str` - Tag to assign
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the tag was not assigned before
+    fn add_tag(&mut self, tag: &str) -> bool {
+        if self.tags.iter().any(|existing| existing == tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        true
+    }
+
+    /// Change the `Item` parent.
+    /// 
+    /// # Arguments
+    /// * parent : `Option<&str>` - Name of the new parent Item or `None` to remove it
+    fn update_parent(&mut self, parent: Option<&str>) {
+        self.parent = parent.map(|name| name.to_string());
+    }
+
+    /// Add a prerequisite to the `Item`. Prerequisites that are already assigned are ignored.
+    /// 
+    /// # Arguments
+    /// * dependency : `&str` - Name of the prerequisite Item
+    fn add_dependency(&mut self, dependency: &str) {
+        if !self.depends_on.iter().any(|existing| existing == dependency) {
+            self.depends_on.push(dependency.to_string());
+        }
+    }
+
+    /// Attach a link to the `Item`. Links that are already attached are ignored.
+    /// Values that do not look like a URL are stored as well, but a warning is printed in the log.
+    /// 
+    /// # Arguments
+    /// * link : `&str` - Link to attach
+    fn add_link(&mut self, link: &str) {
+        if !link.starts_with("http://") && !link.starts_with("https://") {
+            println!("The link {} does not look like a URL", link);
+        }
+        if !self.links.iter().any(|existing| existing == link) {
+            self.links.push(link.to_string());
+        }
+    }
+
+    /// Remove a link from the `Item`.
+    /// 
+    /// # Arguments
+    /// * link : `&str` - Link to remove
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the link was attached to the Item
+    fn remove_link(&mut self, link: &str) -> bool {
+        let count_before = self.links.len();
+        self.links.retain(|existing| existing != link);
+        count_before != self.links.len()
+    }
+
+    /// Attach a file to the `Item`. Paths that are already attached are ignored.
+    /// 
+    /// # Arguments
+    /// * path : `&Path` - Relative or absolute path of the file
+    fn add_attachment(&mut self, path: &Path) {
+        if !self.attachments.iter().any(|existing| existing == path) {
+            self.attachments.push(path.to_path_buf());
         }
     }
 
+    /// Remove an attached file from the `Item`.
+    /// 
+    /// # Arguments
+    /// * path : `&Path` - Path of the file
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the file was attached to the Item
+    fn remove_attachment(&mut self, path: &Path) -> bool {
+        let count_before = self.attachments.len();
+        self.attachments.retain(|existing| existing != path);
+        count_before != self.attachments.len()
+    }
+
+    /// Change the `Item` color label. Labels are stored in lowercase.
+    /// 
+    /// # Arguments
+    /// * label : `Option<&str>` - New color label or `None` to remove the label
+    fn update_label(&mut self, label: Option<&str>) {
+        self.label = label.map(|label| label.trim().to_lowercase()).filter(|label| !label.is_empty());
+    }
+
+    /// Change the `Item` assignee. Surrounding whitespace is removed.
+    /// 
+    /// # Arguments
+    /// * assignee : `Option<&str>` - Name of the new assignee or `None` to unassign the Item
+    fn update_assignee(&mut self, assignee: Option<&str>) {
+        self.assignee = assignee.map(|assignee| assignee.trim().to_string()).filter(|assignee| !assignee.is_empty());
+    }
+
+    /// Change the number of days before the due date at which the reminder is due.
+    /// 
+    /// # Arguments
+    /// * days_before : `Option<u32>` - Reminder offset in days or `None` to remove the reminder
+    fn update_reminder(&mut self, days_before: Option<u32>) {
+        self.reminder_days_before = days_before;
+    }
+
+    /// Pin an unpinned `Item` or unpin a pinned one.
+    fn toggle_pin(&mut self) {
+        self.pinned = !self.pinned
+    }
+
     /// Mark an `Item` as completed.  
+    /// The completion date is set to the current day.
     fn complete_item(&mut self) {
-        self.completed = true
+        self.completed = true;
+        self.completion_date = Some(Local::now().date_naive());
     }
 
-    /// Mark an `Item` as not completed. 
+    /// Mark an `Item` as not completed. The completion date is removed.
     fn open_item(&mut self) {
-        self.completed = false
+        self.completed = false;
+        self.completion_date = None;
     }    
 
 }
 
 impl Display for Item {
+    /// Formats the Item in a single line. The alternate form (`{:#}`) shows the due date
+    /// relative to today (e.g. "in 3 days") followed by the exact date in parentheses.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if let Some(due_date) = self.due_date {
-            write!(f, "Name: {}\tDescription: {}\tPriority: {}\tCreation Date:{}\tDue Date:{}\tCompleted: {}", self.name, self.description, self.priority, self.creation_date, due_date, self.completed)
+            let due_date = if f.alternate() { format!("{} ({})", humanize_date(due_date), due_date) } else { due_date.to_string() };
+            write!(f, "Name: {}\tDescription: {}\tPriority: {} {}\tCreation Date:{} ({}d old)\tDue Date:{}\tCompleted: {}", self.name, self.description, self.priority.symbol(), self.priority, self.creation_date, self.age_days(), due_date, self.completed)?;
         } else {
-            write!(f, "Name: {}\tDescription: {}\tPriority: {}\tCreation Date:{}\tDue Date: NA\tCompleted: {}", self.name, self.description, self.priority, self.creation_date, self.completed)
+            write!(f, "Name: {}\tDescription: {}\tPriority: {} {}\tCreation Date:{} ({}d old)\tDue Date: NA\tCompleted: {}", self.name, self.description, self.priority.symbol(), self.priority, self.creation_date, self.age_days(), self.completed)?;
+        }
+        if !self.tags.is_empty() {
+            write!(f, "\tTags: {}", self.tags.join(", "))?;
+        }
+        if let Some(label) = &self.label {
+            write!(f, "\tLabel: {}", label)?;
         }
+        if let Some(assignee) = &self.assignee {
+            write!(f, "\tAssignee: {}", assignee)?;
+        }
+        if self.pinned {
+            write!(f, "\tPinned")?;
+        }
+        Ok(())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Callback that receives an `Item` after it was completed.
+pub type CompletionCallback = Box<dyn FnMut(&Item)>;
+
+/// Item names and Items of a `ToDoList` in a defined order.
+pub type ItemEntries<'a> = Vec<(&'a String, &'a Item)>;
+
+#[derive(Serialize, Deserialize)]
 /// Representation of a to-do list with multiple items.
 pub struct ToDoList {
     /// Name of the to-do list
@@ -186,7 +700,126 @@ pub struct ToDoList {
     /// Description of the to-do list
     description: String,
     /// Collection of all `Item` structs within the to-do list
+    #[serde(serialize_with = "serialize_sorted_items")]
     items: HashMap<String, Item>,
+    /// Priority assigned to new items when no priority is submitted
+    #[serde(default = "default_list_priority")]
+    default_priority: Priority,
+    /// Optional maximum number of Items in the list
+    #[serde(default)]
+    max_items: Option<usize>,
+    /// Flag to freeze the list, archived lists reject all changes to their Items
+    #[serde(default)]
+    archived: bool,
+    /// Order in which all Items are displayed
+    #[serde(default)]
+    sort_order: SortOrder,
+    /// Flag to display completed Items below all open Items
+    #[serde(default)]
+    completed_last: bool,
+    /// Flag to save the list automatically after every change of an Item
+    #[serde(default)]
+    auto_save: bool,
+    /// Flag to replace Invalid priorities with the Low priority when the list is loaded
+    #[serde(default = "default_normalize_on_load")]
+    normalize_on_load: bool,
+    /// Flag to reject new Items whose due date lies before their creation date
+    #[serde(default)]
+    reject_due_dates_before_creation: bool,
+    /// Flag to display due dates relative to today
+    #[serde(default)]
+    relative_due_dates: bool,
+    /// Categories used to organize many lists
+    #[serde(default)]
+    categories: Vec<String>,
+    /// Optional callback that is invoked whenever an item is completed
+    #[serde(skip)]
+    on_complete: Option<CompletionCallback>,
+}
+
+impl fmt::Debug for ToDoList {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ToDoList")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("items", &self.items)
+            .field("default_priority", &self.default_priority)
+            .field("max_items", &self.max_items)
+            .field("archived", &self.archived)
+            .field("sort_order", &self.sort_order)
+            .field("completed_last", &self.completed_last)
+            .field("auto_save", &self.auto_save)
+            .field("normalize_on_load", &self.normalize_on_load)
+            .field("reject_due_dates_before_creation", &self.reject_due_dates_before_creation)
+            .field("relative_due_dates", &self.relative_due_dates)
+            .field("categories", &self.categories)
+            .field("on_complete", &self.on_complete.as_ref().map(|_| "FnMut(&Item)"))
+            .finish()
+    }
+}
+
+impl PartialEq for ToDoList {
+    /// Compares all stored fields of two lists. The completion callback is not compared.
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.description == other.description
+            && self.items == other.items
+            && self.default_priority == other.default_priority
+            && self.max_items == other.max_items
+            && self.archived == other.archived
+            && self.sort_order == other.sort_order
+            && self.completed_last == other.completed_last
+            && self.auto_save == other.auto_save
+            && self.normalize_on_load == other.normalize_on_load
+            && self.reject_due_dates_before_creation == other.reject_due_dates_before_creation
+            && self.relative_due_dates == other.relative_due_dates
+            && self.categories == other.categories
+    }
+}
+
+/// Serializes a list to JSON, deserializes it again, and asserts that the result equals
+/// the original list. New fields of `ToDoList` and `Item` have to survive this round trip.
+/// 
+/// # Arguments
+/// * list : &ToDoList - List to check
+/// 
+/// # Panics
+/// The function panics if the list cannot be serialized or deserialized or if the
+/// deserialized list differs from the original list.
+#[cfg(test)]
+pub fn assert_round_trip(list: &ToDoList) {
+    let json = serde_json::to_string(list).expect("Unable to serialize the list");
+    let loaded: ToDoList = serde_json::from_str(&json).expect("Unable to deserialize the list");
+    assert_eq!(&loaded, list, "The list changed during the JSON round trip: {}", json);
+}
+
+/// Serializes the items of a list sorted by their names so that saved files are stable.
+/// 
+/// # Arguments
+/// * items : &HashMap<String, Item> - Items to serialize
+/// * serializer : S - Serializer that writes the items
+/// 
+/// # Errors
+/// * Returns the error of the serializer if the items could not be serialized
+fn serialize_sorted_items<S: Serializer>(items: &HashMap<String, Item>, serializer: S) -> Result<S::Ok, S::Error> {
+    let sorted_items: BTreeMap<&String, &Item> = items.iter().collect();
+    sorted_items.serialize(serializer)
+}
+
+/// Provides the default priority of lists that were saved without one.
+/// 
+/// # Returns
+/// * `Priority`: The Medium priority
+fn default_list_priority() -> Priority {
+    Priority::Medium
+}
+
+/// Provides the normalization setting of lists that were saved without one.
+/// 
+/// # Returns
+/// * `bool`: Invalid priorities are normalized by default
+fn default_normalize_on_load() -> bool {
+    true
 }
 
 impl ToDoList {
@@ -200,7 +833,7 @@ impl ToDoList {
     /// # Returns
     /// * `ToDoList`: A new instance of a to-do list   
     pub fn new(list_name: &str, list_description: &str) -> Self {
-        ToDoList { name: list_name.to_string(), description: list_description.to_string(), items: HashMap::new() }
+        ToDoList { name: list_name.to_string(), description: list_description.to_string(), items: HashMap::new(), default_priority: default_list_priority(), max_items: None, archived: false, sort_order: SortOrder::default(), completed_last: false, auto_save: false, normalize_on_load: default_normalize_on_load(), reject_due_dates_before_creation: false, relative_due_dates: false, categories: vec![], on_complete: None }
     }
 
     /// Creates a new `Item` and automatically stores it in the `ToDoList`.
@@ -208,63 +841,445 @@ impl ToDoList {
     /// the same name as the submitted one. If so, it will not create the new Item and instead
     /// return an error.
     /// Submitting the method with `replace` as `true` allows it to replace the existing version.
+    /// An empty priority is replaced with the default priority of the list.
     /// 
     /// # Arguments
     /// * name : &str - Name of the Item
     /// * description : &str - Item description
-    /// * priority : &str - Item priority
+    /// * priority : &str - Item priority (empty for the list default)
     /// * replace: bool - Set to true to replace an existing Item
     /// * due_date_ymd : Option<(i32, u32, u32)> - Item due date (optional)
     /// 
     /// # Errors
     /// * `ToDoSelectionError::ToDoAlreadyPresent`: An Item with the same name already exists in the ToDoList and replace was set to false.  
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    /// * `ToDoSelectionError::InvalidDate`: The submitted values do not form a valid date.
+    /// * `ToDoSelectionError::DueDateBeforeCreation`: The due date lies before today and the list rejects such due dates.
+    /// * `ToDoSelectionError::ListFull`: A new Item would exceed the maximum number of Items of the list.
     pub fn create_item(&mut self, name: &str, description: &str, priority: &str, due_date_ymd: Option<(i32, u32, u32)>, replace: bool) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
         if !self.list_contains_item(name) || replace {
-            self.items.insert(name.to_string(), Item::new(name, description, priority, due_date_ymd));
+            if !self.list_contains_item(name) {
+                self.ensure_capacity()?;
+            }
+            let priority = if priority.trim().is_empty() { self.default_priority.to_string() } else { priority.to_string() };
+            let mut item = Item::new(name, description, &priority, due_date_ymd)?;
+            item.check_due_date(self.reject_due_dates_before_creation)?;
+            // New Items are appended to the custom order, replaced Items keep their position
+            item.order = match self.items.get(name) {
+                Some(existing) => existing.order,
+                None => self.items.values().map(|item| item.order).max().map_or(0, |order| order + 1),
+            };
+            self.items.insert(name.to_string(), item);
             Ok(())
         } else {
             Err(ToDoSelectionError::ToDoAlreadyPresent)
         }
     }
 
-    /// Creates a reference to the `ToDoList` name.
+    /// Creates a new `Item` from a single line of text and stores it in the `ToDoList`.
+    /// Tokens starting with `!` set the priority (e.g. `!high`), tokens starting with `@`
+    /// set an ISO due date (e.g. `@2024-05-01`), and tokens starting with `#` add a tag.
+    /// All other tokens, including ones that cannot be interpreted, form the Item name.
+    /// Items without a priority token are created with the default priority of the list.
     /// 
-    /// # Returns
-    /// * `&str`: ToDoList name    
-    pub fn get_name(&self) -> &str {
-        &self.name
+    /// # Arguments
+    /// * line : &str - Line describing the Item, e.g. `Buy milk !high @2024-05-01 #groceries`
+    /// 
+    /// # Errors
+    /// * Returns an error message if the line contains no name, an Item with the same name already exists,
+    ///   or the due date lies before today and the list rejects such due dates.
+    pub fn quick_add(&mut self, line: &str) -> Result<(), String> {
+        let mut name_parts: Vec<&str> = vec![];
+        let mut builder = ItemBuilder::new().priority(&self.default_priority.to_string());
+        for token in line.split_whitespace() {
+            if let Some(value) = token.strip_prefix('!') && Priority::from_str(value) != Priority::Invalid {
+                builder = builder.priority(value);
+            } else if let Some(value) = token.strip_prefix('@') && let Ok(parsed_date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                builder = builder.due_date((parsed_date.year(), parsed_date.month(), parsed_date.day()));
+            } else if let Some(value) = token.strip_prefix('#') && !value.is_empty() {
+                builder = builder.tag(value);
+            } else {
+                name_parts.push(token);
+            }
+        }
+        let name = name_parts.join(" ");
+        if name.is_empty() {
+            return Err("The submitted line does not contain an item name".to_string());
+        }
+        let item = builder.name(&name).build().map_err(|e| e.to_string())?;
+        self.add_item(item).map_err(|e| e.to_string())
     }
 
-    /// Creates a reference to the `ToDoList` description.
+    /// Stores an `Item`, e.g. one created with an `ItemBuilder`, in the `ToDoList`.
+    /// The Item is appended to the custom order of the list.
     /// 
-    /// # Returns
-    /// * `&str`: ToDoList description      
-    pub fn get_description(&self) -> &str {
-        &self.description
-    }    
+    /// # Arguments
+    /// * item : Item - Item to store
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoAlreadyPresent`: An Item with the same name already exists in the ToDoList.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    /// * `ToDoSelectionError::ListFull`: The list already contains the maximum number of Items.
+    /// * `ToDoSelectionError::DueDateBeforeCreation`: The due date lies before the creation date and the list rejects such due dates.
+    pub fn add_item(&mut self, mut item: Item) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if self.list_contains_item(&item.name) {
+            return Err(ToDoSelectionError::ToDoAlreadyPresent);
+        }
+        self.ensure_capacity()?;
+        item.check_due_date(self.reject_due_dates_before_creation)?;
+        item.order = self.items.values().map(|item| item.order).max().map_or(0, |order| order + 1);
+        self.items.insert(item.name.clone(), item);
+        Ok(())
+    }
 
-    /// Checks whether the item HashMap contains an Item with the submitted name
+    /// Deserializes an `Item` from a JSON string, e.g. one created with `export_item`, and stores
+    /// it in the `ToDoList` under its name. Like `create_item`, an existing Item with the same name
+    /// is only replaced if `replace` is `true`. Imported Items are appended to the custom order,
+    /// replaced Items keep their position.
     /// 
     /// # Arguments
-    /// * item_name : &str - Name of the Item 
+    /// * json : &str - JSON representation of the Item
+    /// * replace: bool - Set to true to replace an existing Item
     /// 
-    /// # Returns
-    /// * `bool`: is `true` if the Item exists    
-    pub fn list_contains_item(&self, item_name: &str) -> bool {
-        self.items.contains_key(item_name)
+    /// # Errors
+    /// * Returns an error message if the JSON is malformed, the list is archived or full, an Item
+    ///   with the same name exists and replace was set to false, or the due date lies before the
+    ///   creation date and the list rejects such due dates
+    pub fn import_item(&mut self, json: &str, replace: bool) -> Result<(), String> {
+        self.ensure_editable().map_err(|e| e.to_string())?;
+        let mut item: Item = serde_json::from_str(json).map_err(|e| format!("The item could not be read from JSON: {}", e))?;
+        item.check_due_date(self.reject_due_dates_before_creation).map_err(|e| e.to_string())?;
+        item.order = match self.items.get(&item.name) {
+            Some(_) if !replace => return Err(ToDoSelectionError::ToDoAlreadyPresent.to_string()),
+            Some(existing) => existing.order,
+            None => {
+                self.ensure_capacity().map_err(|e| e.to_string())?;
+                self.items.values().map(|item| item.order).max().map_or(0, |order| order + 1)
+            },
+        };
+        self.items.insert(item.name.clone(), item);
+        Ok(())
     }
 
-    /// Returns an immutable reference to an `Item` stored in the items field.
+    /// Stores a copy of an existing `Item` under a new name in the `ToDoList`.
+    /// The copy is added with `add_item`, so the same checks apply.
     /// 
     /// # Arguments
-    /// * item_name : &str - Name of the Item 
-    /// 
-    /// # Returns
-    /// * `&Item`: Reference to the Item
+    /// * item : &Item - Item to copy
+    /// * name : &str - Name of the copied Item
     /// 
     /// # Errors
-    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field. 
-    pub fn get_item_ref(&self, item_name: &str) -> Result<&Item, ToDoSelectionError> {
+    /// * `ToDoSelectionError::ToDoAlreadyPresent`: An Item with the same name already exists in the ToDoList.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    /// * `ToDoSelectionError::ListFull`: The list already contains the maximum number of Items.
+    pub fn add_item_copy(&mut self, item: &Item, name: &str) -> Result<(), ToDoSelectionError> {
+        let mut copy = item.clone();
+        copy.name = name.to_string();
+        self.add_item(copy)
+    }
+
+    /// Finds existing Items that are likely duplicates of a new Item. An Item is considered
+    /// similar if its name or description matches after ignoring case and extra whitespace, or
+    /// if at least half of the significant words (longer than 3 characters) of both Items are shared.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the new Item
+    /// * description : &str - Description of the new Item
+    /// 
+    /// # Returns
+    /// * `Vec<&String>`: Alphabetically sorted names of the similar Items
+    pub fn find_similar(&self, name: &str, description: &str) -> Vec<&String> {
+        let normalize = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        let significant_words = |name: &str, description: &str| -> HashSet<String> {
+            format!("{} {}", name, description)
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| word.chars().count() > 3)
+                .map(|word| word.to_lowercase())
+                .collect()
+        };
+        let (name, description) = (normalize(name), normalize(description));
+        let words = significant_words(&name, &description);
+        let mut output: Vec<&String> = self.items.iter()
+            .filter(|(_, item)| {
+                let item_words = significant_words(&item.name, &item.description);
+                let shared = words.intersection(&item_words).count();
+                normalize(&item.name) == name
+                    || (!description.is_empty() && normalize(&item.description) == description)
+                    || (shared > 0 && shared * 2 >= words.len().max(item_words.len()))
+            })
+            .map(|(item_name, _)| item_name)
+            .collect();
+        output.sort();
+        output
+    }
+
+    /// Permanently deletes all Items whose name is empty or only consists of whitespace.
+    /// Archived lists are not changed.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of removed Items
+    pub fn vacuum(&mut self) -> usize {
+        self.retain(|item| !item.name.trim().is_empty())
+    }
+
+    /// Trims leading and trailing whitespace from all Item names. Items whose trimmed
+    /// names collide are merged by keeping the most recently created one. If both were
+    /// created on the same day, an Item whose name did not need trimming is preferred.
+    /// Archived lists are not changed.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items that were merged into another Item
+    pub fn dedupe_trimmed(&mut self) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let mut merged = 0;
+        let mut output: HashMap<String, Item> = HashMap::new();
+        let mut names: Vec<String> = self.items.keys().cloned().collect();
+        // Untrimmed names are processed last so they lose ties against trimmed names
+        names.sort_by_key(|name| (name.trim() != name, name.clone()));
+        for name in names {
+            let mut item = self.items.remove(&name).expect("The list Item does not exist");
+            let trimmed_name = name.trim().to_string();
+            item.name = trimmed_name.clone();
+            if let Some(existing) = output.get(&trimmed_name) {
+                merged += 1;
+                if existing.creation_date >= item.creation_date {
+                    continue;
+                }
+            }
+            output.insert(trimmed_name, item);
+        }
+        self.items = output;
+        merged
+    }
+
+    /// Creates a reference to the `ToDoList` name.
+    /// 
+    /// # Returns
+    /// * `&str`: ToDoList name    
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Creates a reference to the `ToDoList` description.
+    /// 
+    /// # Returns
+    /// * `&str`: ToDoList description      
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }    
+
+    /// Creates a reference to the `ToDoList` default_priority.
+    /// 
+    /// # Returns
+    /// * `&Priority`: Priority assigned to new Items when no priority is submitted
+    pub fn get_default_priority(&self) -> &Priority {
+        &self.default_priority
+    }
+
+    /// Indicates whether the `ToDoList` is archived and therefore read-only.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the list is archived
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Archives the `ToDoList` or makes an archived list editable again.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the list is archived after the change
+    pub fn toggle_archived(&mut self) -> bool {
+        self.archived = !self.archived;
+        self.archived
+    }
+
+    /// Returns the maximum number of Items in the `ToDoList`.
+    /// 
+    /// # Returns
+    /// * `Option<usize>`: Maximum number of Items or `None` if the list has no limit
+    pub fn get_max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+
+    /// Change the maximum number of Items in the `ToDoList`. Existing Items are kept
+    /// even if the list already contains more Items than the new limit allows.
+    /// 
+    /// # Arguments
+    /// * max_items : Option<usize> - Maximum number of Items or `None` to remove the limit
+    pub fn set_max_items(&mut self, max_items: Option<usize>) {
+        self.max_items = max_items;
+    }
+
+    /// Checks that another Item can be added to the `ToDoList`.
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ListFull`: The list already contains the maximum number of Items.
+    fn ensure_capacity(&self) -> Result<(), ToDoSelectionError> {
+        match self.max_items {
+            Some(max_items) if self.items.len() >= max_items => Err(ToDoSelectionError::ListFull),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the `ToDoList` may be changed.
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    fn ensure_editable(&self) -> Result<(), ToDoSelectionError> {
+        if self.archived {
+            Err(ToDoSelectionError::ListArchived)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the order in which all Items of the `ToDoList` are displayed.
+    /// 
+    /// # Returns
+    /// * `SortOrder`: The sort order of the list
+    pub fn get_sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    /// Indicates whether completed Items are displayed below all open Items.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if completed Items are placed at the bottom
+    pub fn is_completed_last(&self) -> bool {
+        self.completed_last
+    }
+
+    /// Switches between displaying completed Items below all open Items and
+    /// displaying them in the regular sort order.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if completed Items are placed at the bottom after the change
+    pub fn toggle_completed_last(&mut self) -> bool {
+        self.completed_last = !self.completed_last;
+        self.completed_last
+    }
+
+    /// Indicates whether the list is saved automatically after every change of an Item.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if auto-save is enabled
+    pub fn is_auto_save(&self) -> bool {
+        self.auto_save
+    }
+
+    /// Enables auto-save if it is disabled or disables it if it is enabled.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if auto-save is enabled after the change
+    pub fn toggle_auto_save(&mut self) -> bool {
+        self.auto_save = !self.auto_save;
+        self.auto_save
+    }
+
+    /// Creates a reference to the categories of the list.
+    /// 
+    /// # Returns
+    /// * `&[String]`: Categories of the list in lowercase
+    pub fn get_categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// Adds a category to the list. Categories are stored in lowercase and
+    /// categories that are already assigned are ignored.
+    /// 
+    /// # Arguments
+    /// * category : &str - Category to add
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the category was added
+    pub fn add_category(&mut self, category: &str) -> bool {
+        let category = category.trim().to_lowercase();
+        if category.is_empty() || self.has_category(&category) {
+            return false;
+        }
+        self.categories.push(category);
+        true
+    }
+
+    /// Removes a category from the list. Categories are compared case-insensitively.
+    /// 
+    /// # Arguments
+    /// * category : &str - Category to remove
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the list carried the category
+    pub fn remove_category(&mut self, category: &str) -> bool {
+        let category = category.trim().to_lowercase();
+        let count_before = self.categories.len();
+        self.categories.retain(|existing| *existing != category);
+        count_before != self.categories.len()
+    }
+
+    /// Indicates whether the list carries a category. Categories are compared case-insensitively.
+    /// 
+    /// # Arguments
+    /// * category : &str - Category to look for
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the list carries the category
+    pub fn has_category(&self, category: &str) -> bool {
+        let category = category.trim().to_lowercase();
+        self.categories.contains(&category)
+    }
+
+    /// Change the order in which all Items of the `ToDoList` are displayed.
+    /// 
+    /// # Arguments
+    /// * sort_order : SortOrder - New sort order of the list
+    pub fn set_sort_order(&mut self, sort_order: SortOrder) {
+        self.sort_order = sort_order;
+    }
+
+    /// Change the `ToDoList` default_priority. The method accepts a `&str` and
+    /// converts it into a `Priority`.
+    /// 
+    /// # Arguments
+    /// * priority : &str - New default priority
+    pub fn set_default_priority(&mut self, priority: &str) {
+        self.default_priority = Priority::from_str(priority);
+    }
+
+    /// Registers a callback that is invoked with the completed Item whenever an Item
+    /// is completed. A previously registered callback is replaced. The callback is not
+    /// saved with the list.
+    /// 
+    /// # Arguments
+    /// * callback : F - Closure that receives the completed Item
+    pub fn set_on_complete<F: FnMut(&Item) + 'static>(&mut self, callback: F) {
+        self.on_complete = Some(Box::new(callback));
+    }
+
+    /// Checks whether the item HashMap contains an Item with the submitted name
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `bool`: is `true` if the Item exists    
+    pub fn list_contains_item(&self, item_name: &str) -> bool {
+        self.items.contains_key(item_name)
+    }
+
+    /// Returns an immutable reference to an `Item` stored in the items field.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `&Item`: Reference to the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field. 
+    pub fn get_item_ref(&self, item_name: &str) -> Result<&Item, ToDoSelectionError> {
         if self.list_contains_item(item_name) {
             Ok(self.items.get(item_name).unwrap())
         } else {
@@ -279,7 +1294,9 @@ impl ToDoList {
     /// 
     /// # Errors
     /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.  
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
     pub fn delete_item(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
         if self.list_contains_item(item_name) {
             self.items.remove(item_name);
             Ok(())
@@ -288,6 +1305,160 @@ impl ToDoList {
         }
     }
 
+    /// Renames an Item in the item HashMap. Parent and prerequisite references of other Items
+    /// are updated to the new name.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Current name of the Item 
+    /// * new_name : &str - New name of the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ToDoAlreadyPresent`: An Item with the new name already exists.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn rename_item(&mut self, item_name: &str, new_name: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if self.list_contains_item(new_name) {
+            return Err(ToDoSelectionError::ToDoAlreadyPresent);
+        }
+        let mut item = self.items.remove(item_name).ok_or(ToDoSelectionError::ToDoNotFound)?;
+        item.name = new_name.to_string();
+        self.items.insert(new_name.to_string(), item);
+        for other in self.items.values_mut() {
+            if other.parent.as_deref() == Some(item_name) {
+                other.parent = Some(new_name.to_string());
+            }
+            for dependency in other.depends_on.iter_mut().filter(|dependency| *dependency == item_name) {
+                *dependency = new_name.to_string();
+            }
+        }
+        Ok(())
+    }
+
+    /// Renames every Item whose name starts with a prefix by replacing the prefix, e.g. from
+    /// "old:" to "new:". See `rename_item`. Renames that would replace an existing Item or
+    /// result in an empty name are skipped. An empty old prefix matches no Item.
+    /// 
+    /// # Arguments
+    /// * old_prefix : &str - Prefix of the renamed Items
+    /// * new_prefix : &str - Replacement of the prefix
+    /// 
+    /// # Returns
+    /// * `usize`: Number of renamed Items
+    pub fn rename_items_with_prefix(&mut self, old_prefix: &str, new_prefix: &str) -> usize {
+        if old_prefix.is_empty() {
+            return 0;
+        }
+        let mut matching: Vec<String> = self.items.keys().filter(|name| name.starts_with(old_prefix)).cloned().collect();
+        matching.sort();
+        let mut count = 0;
+        for item_name in matching {
+            let new_name = format!("{}{}", new_prefix, &item_name[old_prefix.len()..]);
+            if new_name.is_empty() || new_name == item_name {
+                continue;
+            }
+            if self.rename_item(&item_name, &new_name).is_ok() {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Moves an Item from the ToDoList into a trash list and records the name of the
+    /// ToDoList in the Item. The Item is stored in the trash under the key `"<list>: <name>"`.
+    /// If the key is already taken, a counter is appended (e.g. `"<list>: <name> (2)"`) so that
+    /// no Item in the trash is ever replaced.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * trash : &mut ToDoList - List that collects deleted Items
+    /// 
+    /// # Returns
+    /// * `String`: Key of the Item in the trash
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn trash_item(&mut self, item_name: &str, trash: &mut ToDoList) -> Result<String, ToDoSelectionError> {
+        self.ensure_editable()?;
+        let mut item = self.items.remove(item_name).ok_or(ToDoSelectionError::ToDoNotFound)?;
+        item.origin_list = Some(self.name.clone());
+        let base_key = format!("{}: {}", self.name, item_name);
+        let mut trash_key = base_key.clone();
+        let mut counter = 2;
+        while trash.list_contains_item(&trash_key) {
+            trash_key = format!("{} ({})", base_key, counter);
+            counter += 1;
+        }
+        trash.items.insert(trash_key.clone(), item);
+        Ok(trash_key)
+    }
+
+    /// Moves an Item from a trash list back into a regular ToDoList. The Item is stored
+    /// under its original name and the recorded name of the original list is removed from the Item.
+    /// 
+    /// # Arguments
+    /// * trash_key : &str - Key of the Item in the trash, see `trash_item`
+    /// * target : &mut ToDoList - List that receives the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted key exists in the trash.
+    /// * `ToDoSelectionError::ToDoAlreadyPresent`: The target list already contains an Item with the same name.
+    /// * `ToDoSelectionError::ListArchived`: The trash or the target list is archived.
+    /// * `ToDoSelectionError::ListFull`: The target list already contains the maximum number of Items.
+    pub fn restore_trashed_item(&mut self, trash_key: &str, target: &mut ToDoList) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        target.ensure_editable()?;
+        let item_name = self.get_item_ref(trash_key)?.name.clone();
+        if target.list_contains_item(&item_name) {
+            return Err(ToDoSelectionError::ToDoAlreadyPresent);
+        }
+        target.ensure_capacity()?;
+        let mut item = self.items.remove(trash_key).expect("The list Item does not exist");
+        item.origin_list = None;
+        target.items.insert(item_name, item);
+        Ok(())
+    }
+
+    /// Keeps only the Items for which the predicate returns `true` and permanently
+    /// deletes all others from the item HashMap. Archived lists are not changed.
+    ///
+    /// # Arguments
+    /// * predicate : F - Closure that decides whether an Item is kept
+    ///
+    /// # Returns
+    /// * `usize`: Number of removed Items
+    pub fn retain<F: Fn(&Item) -> bool>(&mut self, predicate: F) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let count_before = self.items.len();
+        self.items.retain(|_, item| predicate(item));
+        count_before - self.items.len()
+    }
+
+    /// Permanently deletes all Items while the name, description, and settings of the list are kept.
+    /// Archived lists are not changed.
+    ///
+    /// # Returns
+    /// * `usize`: Number of removed Items
+    pub fn clear_all_items(&mut self) -> usize {
+        self.retain(|_| false)
+    }
+
+    /// Permanently deletes completed Items that were completed before a cutoff date.
+    /// Items without a completion date are judged by their creation date instead.
+    /// Open Items are never deleted. Archived lists are not changed.
+    ///
+    /// # Arguments
+    /// * cutoff : NaiveDate - Items completed before this date are deleted
+    ///
+    /// # Returns
+    /// * `usize`: Number of removed Items
+    pub fn purge_completed_before(&mut self, cutoff: NaiveDate) -> usize {
+        self.retain(|item| !item.is_completed() || item.completion_date.unwrap_or(item.creation_date) >= cutoff)
+    }
+
     /// Change the description of an Item in the item HashMap if it exists. If not, the method returns an error instead.
     /// 
     /// # Arguments
@@ -296,7 +1467,9 @@ impl ToDoList {
     /// 
     /// # Errors
     /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.      
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
     pub fn update_item_description(&mut self, item_name: &str, new_description: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
         if let Some(item) = self.items.get_mut(item_name) {
             item.update_description(new_description);
             Ok(())
@@ -313,7 +1486,9 @@ impl ToDoList {
     /// 
     /// # Errors
     /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.     
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
     pub fn update_item_priority(&mut self, item_name: &str, new_priority: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
         if let Some(item) = self.items.get_mut(item_name) {
             item.update_priority(new_priority);
             Ok(())
@@ -322,8 +1497,31 @@ impl ToDoList {
         }
     }
 
+    /// Change the priority of every Item for which the predicate returns `true`.
+    /// Archived lists are not changed.
+    /// 
+    /// # Arguments
+    /// * predicate : F - Closure that selects the Items to update
+    /// * priority : &str - Updated Priority of the selected Items
+    /// 
+    /// # Returns
+    /// * `usize`: Number of updated Items
+    pub fn set_priority_where<F: Fn(&Item) -> bool>(&mut self, predicate: F, priority: &str) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let mut count = 0;
+        for item in self.items.values_mut() {
+            if predicate(item) {
+                item.update_priority(priority);
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Change the due date of an Item in the item HashMap if it exists. If not, the method returns an error instead.
-    /// If an invalid date is submitted, the method will not update the Item and print a message in the log.
+    /// If an invalid date is submitted, the method will not update the Item and return an error instead.
     /// 
     /// # Arguments
     /// * item_name : &str - Name of the Item 
@@ -331,93 +1529,1379 @@ impl ToDoList {
     /// 
     /// # Errors
     /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.     
+    /// * `ToDoSelectionError::InvalidDate`: The submitted values do not form a valid date.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
     pub fn update_item_due_date(&mut self, item_name: &str, ymd: (i32, u32, u32)) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
         if let Some(item) = self.items.get_mut(item_name) {
-            item.update_due_date(ymd);
-            Ok(())
+            item.update_due_date(ymd)
         } else {
             Err(ToDoSelectionError::ToDoNotFound)
         }
     }    
 
-    /// Mark a list Item as completed if it exists. If not, the method returns an error instead.
+    /// Change the parent of an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// An Item cannot be its own parent.
     /// 
     /// # Arguments
     /// * item_name : &str - Name of the Item 
+    /// * parent : Option<&str> - Name of the parent Item or `None` to turn the Item into a top-level Item
     /// 
     /// # Errors
-    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.    
-    pub fn close_list_item(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+    /// * `ToDoSelectionError::ToDoNotFound`: The Item or the submitted parent does not exist in the `item` field.     
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn update_item_parent(&mut self, item_name: &str, parent: Option<&str>) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(parent_name) = parent && (parent_name == item_name || !self.list_contains_item(parent_name)) {
+            return Err(ToDoSelectionError::ToDoNotFound);
+        }
         if let Some(item) = self.items.get_mut(item_name) {
-            item.complete_item();
+            item.update_parent(parent);
             Ok(())
         } else {
             Err(ToDoSelectionError::ToDoNotFound)
-        }        
+        }
     }
 
-    /// Mark a list Item as uncompleted if it exists. If not, the method returns an error instead.
+    /// Returns all Items whose parent is the submitted Item.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the parent Item 
+    /// 
+    /// # Returns
+    /// * `Vec<&Item>`: Child Items sorted alphabetically by name
+    pub fn children_of(&self, name: &str) -> Vec<&Item> {
+        Self::list_all_items(&self.items)
+            .into_iter()
+            .filter(|item| item.1.parent.as_deref() == Some(name))
+            .map(|item| item.1)
+            .collect()
+    }
+
+    /// Add a prerequisite to an Item in the item HashMap. The Item cannot be completed
+    /// before all of its prerequisites are completed. If either Item does not exist or
+    /// both names are the same, the method returns an error instead.
     /// 
     /// # Arguments
     /// * item_name : &str - Name of the Item 
+    /// * dependency : &str - Name of the prerequisite Item
     /// 
     /// # Errors
-    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.     
-    pub fn open_list_item(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+    /// * `ToDoSelectionError::ToDoNotFound`: The Item or the prerequisite does not exist in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn add_item_dependency(&mut self, item_name: &str, dependency: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if item_name == dependency || !self.list_contains_item(dependency) {
+            return Err(ToDoSelectionError::ToDoNotFound);
+        }
         if let Some(item) = self.items.get_mut(item_name) {
-            item.open_item();
+            item.add_dependency(dependency);
             Ok(())
         } else {
             Err(ToDoSelectionError::ToDoNotFound)
         }
     }
 
-    /// Creates a new version of the Item list in which only
-    /// open Items are being kept.
+    /// Attach a link to an Item in the item HashMap if it exists. If not, the method returns an error instead.
     /// 
-    /// # Returns
-    /// * `HashMap<String, Item>`: Filtered item list     
-    pub fn filter_open_items(&self) -> HashMap<String, Item> {
-        let mut output: HashMap<String, Item> = HashMap::new();
-        for item in &self.items {
-            if !item.1.is_completed() {
-                output.insert(item.0.clone(), item.1.clone());
-            }
-        }        
-        output
-    }
-
-    /// Creates a new version of the Item list in which only
-    /// overdue and open Items are being kept.
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * link : &str - Link to attach
     /// 
-    /// # Returns
-    /// * `HashMap<String, Item>`: Filtered item list
-    pub fn filter_overdue_items(&self) -> HashMap<String, Item> {
-        let mut output: HashMap<String, Item> = HashMap::new();
-        for item in &self.items {
-            if !item.1.is_completed() && item.1.is_overdue() {
-                output.insert(item.0.clone(), item.1.clone());
-            }
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn add_item_link(&mut self, item_name: &str, link: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.add_link(link);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
         }
-        output
+    }
+
+    /// Remove a link from an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * link : &str - Link to remove
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the link was attached to the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn remove_item_link(&mut self, item_name: &str, link: &str) -> Result<bool, ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            Ok(item.remove_link(link))
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Attach a file to an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// The file does not have to exist when it is attached.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * path : &Path - Relative or absolute path of the file
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn add_item_attachment(&mut self, item_name: &str, path: &Path) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.add_attachment(path);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Remove an attached file from an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * path : &Path - Path of the file
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the file was attached to the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn remove_item_attachment(&mut self, item_name: &str, path: &Path) -> Result<bool, ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            Ok(item.remove_attachment(path))
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Change the reminder offset of an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * days_before : Option<u32> - Number of days before the due date or `None` to remove the reminder
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn update_item_reminder(&mut self, item_name: &str, days_before: Option<u32>) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.update_reminder(days_before);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Change the color label of an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * label : Option<&str> - New color label or `None` to remove the label
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn update_item_label(&mut self, item_name: &str, label: Option<&str>) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.update_label(label);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Change the assignee of an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * assignee : Option<&str> - Name of the new assignee or `None` to unassign the Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn update_item_assignee(&mut self, item_name: &str, assignee: Option<&str>) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.update_assignee(assignee);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Appends a dated comment to an Item in the item HashMap if it exists. See `Item::add_comment`.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * text : &str - Text of the comment
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn add_item_comment(&mut self, item_name: &str, text: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.add_comment(text);
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Pin or unpin an Item in the item HashMap if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if the Item is pinned after the change
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn toggle_item_pin(&mut self, item_name: &str) -> Result<bool, ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.toggle_pin();
+            Ok(item.is_pinned())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Moves an Item one position up in the custom order by swapping its position with
+    /// the previous Item. If the Item does not exist, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `bool`: Is `false` if the Item already is the first Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn move_item_up(&mut self, item_name: &str) -> Result<bool, ToDoSelectionError> {
+        self.ensure_editable()?;
+        self.move_item(item_name, true)
+    }
+
+    /// Moves an Item one position down in the custom order by swapping its position with
+    /// the next Item. If the Item does not exist, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `bool`: Is `false` if the Item already is the last Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn move_item_down(&mut self, item_name: &str) -> Result<bool, ToDoSelectionError> {
+        self.ensure_editable()?;
+        self.move_item(item_name, false)
+    }
+
+    /// Swaps the position of an Item with its neighbor in the custom order.
+    /// The positions of all Items are renumbered first so that Items from older
+    /// files without distinct positions can be moved as well.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * up : bool - Set to true to swap with the previous Item instead of the next one
+    /// 
+    /// # Returns
+    /// * `bool`: Is `false` if the Item has no neighbor in the requested direction
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    fn move_item(&mut self, item_name: &str, up: bool) -> Result<bool, ToDoSelectionError> {
+        let mut names: Vec<String> = Self::sort_items_by_order(&self.items).into_iter().map(|item| item.0.clone()).collect();
+        let position = names.iter().position(|name| name == item_name).ok_or(ToDoSelectionError::ToDoNotFound)?;
+        let neighbor = if up { position.checked_sub(1) } else { Some(position + 1).filter(|next| *next < names.len()) };
+        let Some(neighbor) = neighbor else {
+            return Ok(false);
+        };
+        names.swap(position, neighbor);
+        for (order, name) in names.iter().enumerate() {
+            self.items.get_mut(name).expect("The list Item does not exist").order = order as u32;
+        }
+        Ok(true)
+    }
+
+    /// Returns the names of all prerequisites of an Item that are not completed yet.
+    /// Prerequisites that no longer exist in the list are ignored.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Returns
+    /// * `Vec<String>`: Names of the incomplete prerequisites
+    pub fn incomplete_dependencies(&self, item_name: &str) -> Vec<String> {
+        match self.items.get(item_name) {
+            Some(item) => item.depends_on
+                .iter()
+                .filter(|dependency| self.items.get(*dependency).is_some_and(|dependency| !dependency.is_completed()))
+                .cloned()
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// Collects the names of all Items whose description is empty or only contains whitespace.
+    /// 
+    /// # Returns
+    /// * `Vec<&String>`: Alphabetically sorted names of the Items without a description
+    pub fn items_missing_description(&self) -> Vec<&String> {
+        let mut names: Vec<&String> = self.items
+            .iter()
+            .filter(|(_, item)| item.description.trim().is_empty())
+            .map(|(name, _)| name)
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Detects circular dependencies between Items with a depth-first search over the
+    /// prerequisites of every Item. Dependencies on Items that do not exist are ignored.
+    /// 
+    /// # Returns
+    /// * `Vec<Vec<String>>`: One chain of Item names per cycle. Every Item in a chain depends on
+    ///   the next one and the last Item depends on the first. Each chain starts with its alphabetically
+    ///   first Item.
+    pub fn find_dependency_cycles(&self) -> Vec<Vec<String>> {
+        let mut cycles: Vec<Vec<String>> = vec![];
+        let mut finished: HashSet<&str> = HashSet::new();
+        for (name, _) in sort_list(&self.items) {
+            if !finished.contains(name.as_str()) {
+                self.visit_dependencies(name, &mut vec![], &mut finished, &mut cycles);
+            }
+        }
+        cycles
+    }
+
+    /// Visits the prerequisites of an Item for `find_dependency_cycles` and records every
+    /// dependency that leads back to an Item on the current path.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the visited Item
+    /// * path : &mut Vec<&str> - Items on the path from the start of the search to the visited Item
+    /// * finished : &mut HashSet<&str> - Items whose prerequisites were searched completely
+    /// * cycles : &mut Vec<Vec<String>> - Detected cycles
+    fn visit_dependencies<'a>(&'a self, name: &'a str, path: &mut Vec<&'a str>, finished: &mut HashSet<&'a str>, cycles: &mut Vec<Vec<String>>) {
+        let Some(item) = self.items.get(name) else {
+            return;
+        };
+        path.push(name);
+        for dependency in &item.depends_on {
+            if !self.list_contains_item(dependency) {
+                continue;
+            }
+            if let Some(position) = path.iter().position(|visited| visited == dependency) {
+                let mut cycle: Vec<String> = path[position..].iter().map(|name| name.to_string()).collect();
+                let first = (0..cycle.len()).min_by_key(|index| &cycle[*index]).unwrap_or(0);
+                cycle.rotate_left(first);
+                cycles.push(cycle);
+            } else if !finished.contains(dependency.as_str()) {
+                self.visit_dependencies(dependency, path, finished, cycles);
+            }
+        }
+        path.pop();
+        finished.insert(name);
+    }
+
+    /// Applies several field changes to an Item in the item HashMap at once. Only the fields
+    /// of the `ItemUpdate` that are `Some` are changed. All changes are validated first, so the
+    /// Item stays unchanged if any of them is invalid.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * update : ItemUpdate - Field changes to apply
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::InvalidDate`: The submitted due date is not a valid date.
+    /// * `ToDoSelectionError::DependenciesIncomplete`: The Item should be completed but has incomplete prerequisites.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn apply_update(&mut self, item_name: &str, update: ItemUpdate) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if !self.list_contains_item(item_name) {
+            return Err(ToDoSelectionError::ToDoNotFound);
+        }
+        if let Some(ymd) = update.due_date && NaiveDate::from_ymd_opt(ymd.0, ymd.1, ymd.2).is_none() {
+            return Err(ToDoSelectionError::InvalidDate);
+        }
+        if update.completed == Some(true) && !self.incomplete_dependencies(item_name).is_empty() {
+            return Err(ToDoSelectionError::DependenciesIncomplete);
+        }
+        let item = self.items.get_mut(item_name).expect("The list Item does not exist");
+        if let Some(description) = update.description {
+            item.update_description(&description);
+        }
+        if let Some(priority) = update.priority {
+            item.update_priority(&priority);
+        }
+        if let Some(ymd) = update.due_date {
+            item.update_due_date(ymd)?;
+        }
+        match update.completed {
+            Some(true) => {
+                item.complete_item();
+                if let Some(callback) = self.on_complete.as_mut() {
+                    callback(item);
+                }
+            },
+            Some(false) => item.open_item(),
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Mark a list Item as completed if it exists. If not, the method returns an error instead.
+    /// An Item with incomplete prerequisites can only be completed if `force` is `true`.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// * force : bool - Set to true to complete the Item regardless of its prerequisites
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.    
+    /// * `ToDoSelectionError::DependenciesIncomplete`: The Item has prerequisites that are not completed and force was set to false.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn close_list_item(&mut self, item_name: &str, force: bool) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if !force && !self.incomplete_dependencies(item_name).is_empty() {
+            return Err(ToDoSelectionError::DependenciesIncomplete);
+        }
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.complete_item();
+            if let Some(callback) = self.on_complete.as_mut() {
+                callback(item);
+            }
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }        
+    }
+
+    /// Mark a list Item as uncompleted if it exists. If not, the method returns an error instead.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.     
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn open_list_item(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.open_item();
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Replaces the Invalid priority of every affected Item with the Low priority.
+    /// Archived lists are not changed.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items whose priority was fixed
+    pub fn normalize_priorities(&mut self) -> usize {
+        self.reset_invalid_to(Priority::Low)
+    }
+
+    /// Replaces the Invalid priority of every affected Item with the submitted priority.
+    /// The original priority text is not kept for Invalid Items, so all of them receive the same priority.
+    /// Archived lists are not changed.
+    /// 
+    /// # Arguments
+    /// * priority : Priority - Priority assigned to the Items with an Invalid priority
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items whose priority was fixed
+    pub fn reset_invalid_to(&mut self, priority: Priority) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let mut count = 0;
+        for item in self.items.values_mut().filter(|item| item.priority == Priority::Invalid) {
+            item.priority = priority.clone();
+            count += 1;
+        }
+        count
+    }
+
+    /// Indicates whether Invalid priorities are replaced with the Low priority when the list is loaded.
+    /// If disabled, the affected Items are only reported in the log.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if Invalid priorities are normalized on load
+    pub fn is_normalize_on_load(&self) -> bool {
+        self.normalize_on_load
+    }
+
+    /// Enables the normalization of Invalid priorities on load if it is disabled or disables it if it is enabled.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if Invalid priorities are normalized on load after the change
+    pub fn toggle_normalize_on_load(&mut self) -> bool {
+        self.normalize_on_load = !self.normalize_on_load;
+        self.normalize_on_load
+    }
+
+    /// Indicates whether new Items with a due date before their creation date are rejected.
+    /// If disabled, the Items are added and a warning is printed in the log.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if such Items are rejected
+    pub fn is_reject_due_dates_before_creation(&self) -> bool {
+        self.reject_due_dates_before_creation
+    }
+
+    /// Enables the rejection of due dates before the creation date if it is disabled or disables it if it is enabled.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if such Items are rejected after the change
+    pub fn toggle_reject_due_dates_before_creation(&mut self) -> bool {
+        self.reject_due_dates_before_creation = !self.reject_due_dates_before_creation;
+        self.reject_due_dates_before_creation
+    }
+
+    /// Indicates whether due dates are displayed relative to today (e.g. "in 3 days").
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if due dates are displayed relative to today
+    pub fn is_relative_due_dates(&self) -> bool {
+        self.relative_due_dates
+    }
+
+    /// Enables relative due dates if they are disabled or disables them if they are enabled.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if due dates are displayed relative to today after the change
+    pub fn toggle_relative_due_dates(&mut self) -> bool {
+        self.relative_due_dates = !self.relative_due_dates;
+        self.relative_due_dates
+    }
+
+    /// Formats an Item in a single line according to the display settings of the list.
+    /// If relative due dates are enabled, the due date is shown relative to today and
+    /// the exact date is added in parentheses.
+    /// 
+    /// # Arguments
+    /// * item : &Item - Item to format
+    /// 
+    /// # Returns
+    /// * `String`: The formatted Item
+    pub fn format_item(&self, item: &Item) -> String {
+        if self.relative_due_dates {
+            format!("{:#}", item)
+        } else {
+            item.to_string()
+        }
+    }
+
+    /// Raises the priority of every open and overdue Item by one step.
+    /// Items that already have the High priority stay unchanged. Archived lists are not changed.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items whose priority was raised
+    pub fn escalate_overdue(&mut self) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let mut count = 0;
+        for item in self.items.values_mut() {
+            if !item.is_completed() && item.is_overdue() && item.escalate_priority() {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Marks the start of the work on an Item in the item HashMap if it exists. See `Item::start`.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn start_item(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.start();
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Moves the due date of an Item in the item HashMap to the first day of the next month.
+    /// See `Item::defer_to_next_month`.
+    /// 
+    /// # Arguments
+    /// * item_name : &str - Name of the Item 
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::ToDoNotFound`: No Item with the submitted name exists in the `item` field.
+    /// * `ToDoSelectionError::ListArchived`: The list is archived.
+    pub fn defer_item_to_next_month(&mut self, item_name: &str) -> Result<(), ToDoSelectionError> {
+        self.ensure_editable()?;
+        if let Some(item) = self.items.get_mut(item_name) {
+            item.defer_to_next_month();
+            Ok(())
+        } else {
+            Err(ToDoSelectionError::ToDoNotFound)
+        }
+    }
+
+    /// Moves the due date of every Item that falls on a weekend to the following Monday.
+    /// See `Item::move_due_to_next_weekday`. Archived lists are not changed.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items whose due date was moved
+    pub fn move_due_dates_to_weekdays(&mut self) -> usize {
+        if self.ensure_editable().is_err() {
+            return 0;
+        }
+        let mut count = 0;
+        for item in self.items.values_mut() {
+            if item.move_due_to_next_weekday() {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Counts the Items for which the submitted predicate returns `true`.
+    /// 
+    /// # Arguments
+    /// * predicate : F - Closure that decides whether an Item is counted
+    /// 
+    /// # Returns
+    /// * `usize`: Number of matching Items
+    pub fn count_where<F: Fn(&Item) -> bool>(&self, predicate: F) -> usize {
+        self.items.values().filter(|item| predicate(item)).count()
+    }
+
+    /// Counts the open Items whose due date lies between today and the
+    /// submitted number of days from now (both inclusive). Overdue Items are not counted.
+    /// 
+    /// # Arguments
+    /// * days : i64 - Number of days after today that are still counted
+    /// 
+    /// # Returns
+    /// * `usize`: Number of open Items due within the period
+    pub fn due_within(&self, days: i64) -> usize {
+        let today = Local::now().date_naive();
+        // Periods beyond the supported date range count every future or no Item
+        let last_day = Duration::try_days(days)
+            .and_then(|period| today.checked_add_signed(period))
+            .unwrap_or(if days < 0 { NaiveDate::MIN } else { NaiveDate::MAX });
+        self.count_where(|item| !item.is_completed() && item.due_date.is_some_and(|due_date| due_date >= today && due_date <= last_day))
+    }
+
+    /// Counts the Items of the ToDoList by their state.
+    /// 
+    /// # Returns
+    /// * `ListStats`: Number of total, open, completed, and overdue Items and of the Items due this week
+    pub fn stats(&self) -> ListStats {
+        let mut stats = ListStats { total: self.items.len(), due_this_week: self.due_within(7), ..ListStats::default() };
+        for item in self.items.values() {
+            if item.is_completed() {
+                stats.completed += 1;
+            } else {
+                stats.open += 1;
+                if item.is_overdue() {
+                    stats.overdue += 1;
+                }
+            }
+        }
+        stats
+    }
+
+    /// Sums the number of words in the descriptions of all Items.
+    /// See `Item::description_word_count`.
+    /// 
+    /// # Returns
+    /// * `usize`: Number of words in all descriptions
+    pub fn total_description_words(&self) -> usize {
+        self.items.values().map(|item| item.description_word_count()).sum()
+    }
+
+    /// Calculates the share of completed Items for today so it can be recorded in a history.
+    /// An empty list has a completion rate of 0%.
+    /// 
+    /// # Returns
+    /// * `(NaiveDate, f64)`: Today's date and the percentage of completed Items (0 to 100)
+    pub fn completion_snapshot(&self) -> (NaiveDate, f64) {
+        let stats = self.stats();
+        let percentage = if stats.total == 0 { 0.0 } else { stats.completed as f64 * 100.0 / stats.total as f64 };
+        (Local::now().date_naive(), percentage)
+    }
+
+    /// Projects the day on which all open Items are completed if the submitted number of Items
+    /// is completed per day. Partial days are rounded up to a full day.
+    /// 
+    /// # Arguments
+    /// * per_day : f64 - Number of Items completed per day
+    /// 
+    /// # Returns
+    /// * `Option<NaiveDate>`: Projected completion date or `None` if no Item is open, the rate is not positive,
+    ///   or the date lies beyond the supported date range
+    pub fn projected_completion(&self, per_day: f64) -> Option<NaiveDate> {
+        let open = self.count_where(|item| !item.is_completed());
+        if open == 0 || !per_day.is_finite() || per_day <= 0.0 {
+            return None;
+        }
+        let days = (open as f64 / per_day).ceil() as i64;
+        Local::now().date_naive().checked_add_signed(Duration::try_days(days)?)
+    }
+
+    /// Counts the open Items by the state of their due date in a single pass.
+    /// Completed Items are not counted.
+    /// 
+    /// # Returns
+    /// * `DueBreakdown`: Number of overdue, due today, upcoming, and undated open Items
+    pub fn due_breakdown(&self) -> DueBreakdown {
+        let today = Local::now().date_naive();
+        let mut breakdown = DueBreakdown::default();
+        for item in self.items.values().filter(|item| !item.is_completed()) {
+            match item.due_date {
+                Some(due_date) if due_date < today => breakdown.overdue += 1,
+                Some(due_date) if due_date == today => breakdown.due_today += 1,
+                Some(_) => breakdown.upcoming += 1,
+                None => breakdown.no_date += 1,
+            }
+        }
+        breakdown
+    }
+
+    /// Counts the completed Items per completion day. Items that were completed
+    /// before completion dates were recorded are not counted.
+    /// 
+    /// # Returns
+    /// * `BTreeMap<NaiveDate, usize>`: Number of completed Items by day, ordered by date
+    pub fn completed_by_day(&self) -> BTreeMap<NaiveDate, usize> {
+        let mut output: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+        for item in self.items.values() {
+            if item.is_completed() && let Some(completion_date) = item.completion_date {
+                *output.entry(completion_date).or_insert(0) += 1;
+            }
+        }
+        output
+    }
+
+    /// Counts the consecutive days ending today on which at least one Item was completed.
+    /// 
+    /// # Returns
+    /// * `u32`: Length of the current completion streak in days
+    pub fn current_streak(&self) -> u32 {
+        let completed_by_day = self.completed_by_day();
+        let mut day = Local::now().date_naive();
+        let mut streak = 0;
+        while completed_by_day.contains_key(&day) {
+            streak += 1;
+            day -= Duration::days(1);
+        }
+        streak
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// open Items are being kept.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list     
+    pub fn filter_open_items(&self) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if !item.1.is_completed() {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }        
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// Items with the submitted color label are being kept. Labels are compared case-insensitively.
+    /// 
+    /// # Arguments
+    /// * label : &str - Color label of the kept Items
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn filter_by_label(&self, label: &str) -> HashMap<String, Item> {
+        let label = label.trim().to_lowercase();
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if item.1.label.as_ref() == Some(&label) {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// overdue and open Items are being kept.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn filter_overdue_items(&self) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if !item.1.is_completed() && item.1.is_overdue() {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only Items matching the submitted
+    /// keywords are being kept. The keywords are separated by whitespace and searched
+    /// case-insensitively in the name and the description of every Item.
+    /// 
+    /// # Arguments
+    /// * query : &str - Keywords separated by whitespace
+    /// * mode : SearchMode - `All` keeps Items containing every keyword, `Any` keeps Items containing at least one
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list, empty if the query contains no keywords
+    pub fn search_items_advanced(&self, query: &str, mode: SearchMode) -> HashMap<String, Item> {
+        let keywords: Vec<String> = query.split_whitespace().map(|keyword| keyword.to_lowercase()).collect();
+        let mut output: HashMap<String, Item> = HashMap::new();
+        if keywords.is_empty() {
+            return output;
+        }
+        for item in &self.items {
+            let text = format!("{} {}", item.0, item.1.description).to_lowercase();
+            let matches = match mode {
+                SearchMode::All => keywords.iter().all(|keyword| text.contains(keyword.as_str())),
+                SearchMode::Any => keywords.iter().any(|keyword| text.contains(keyword.as_str())),
+            };
+            if matches {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// Items that were started but are not completed are being kept. See `Item::is_in_progress`.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn in_progress_items(&self) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if item.1.is_in_progress() {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// open Items due on a Saturday or Sunday are being kept. See `Item::is_due_on_weekend`.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn items_due_on_weekend(&self) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if !item.1.is_completed() && item.1.is_due_on_weekend() {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Adds a tag to every Item whose name or description contains all keywords of the query.
+    /// See `search_items_advanced`. Archived lists are not changed.
+    /// 
+    /// # Arguments
+    /// * query : &str - Keywords separated by whitespace
+    /// * tag : &str - Tag to assign
+    /// 
+    /// # Returns
+    /// * `usize`: Number of Items that received the tag
+    pub fn tag_matching(&mut self, query: &str, tag: &str) -> usize {
+        let tag = tag.trim();
+        if tag.is_empty() || self.ensure_editable().is_err() {
+            return 0;
+        }
+        let matching: Vec<String> = self.search_items_advanced(query, SearchMode::All).into_keys().collect();
+        let mut count = 0;
+        for item_name in matching {
+            if let Some(item) = self.items.get_mut(&item_name) && item.add_tag(tag) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// open Items older than the submitted number of days are being kept.
+    /// 
+    /// # Arguments
+    /// * older_than_days : i64 - Minimum age (exclusive) of the kept Items in days
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn stale_items(&self, older_than_days: i64) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if !item.1.is_completed() && item.1.age_days() > older_than_days {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// Items that are due today are being kept.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn filter_due_today_items(&self) -> HashMap<String, Item> {
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if item.1.is_due_today() {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
+    }
+
+    /// Creates a new version of the Item list in which only
+    /// open Items whose reminder is due today are being kept.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, Item>`: Filtered item list
+    pub fn reminders_due_today(&self) -> HashMap<String, Item> {
+        let today = Local::now().date_naive();
+        let mut output: HashMap<String, Item> = HashMap::new();
+        for item in &self.items {
+            if !item.1.is_completed() && item.1.reminder_date() == Some(today) {
+                output.insert(item.0.clone(), item.1.clone());
+            }
+        }
+        output
     }
 
     /// Converts an item HashMap into a Vector in which the original entries are
-    /// stored in tuples. The items in the resulting vector are sorted alphabetically
-    /// based on the Item names.
+    /// stored in tuples. Pinned items are placed above all other items. Within both
+    /// groups, the items are sorted alphabetically based on the Item names, ignoring
+    /// case and accents (see `compare_folded`).
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Sorted Vector representing the inserted HashMap      
+    pub fn list_all_items (hash_map: &HashMap<String, Item>) -> Vec<(&String, &Item)> {
+        let mut output = sort_list_folded(hash_map);
+        // The sort is stable, so the alphabetical order is kept within both groups
+        output.sort_by_key(|item| !item.1.is_pinned());
+        output
+    }         
+
+    /// Creates a read-only view of the ToDoList that can be passed to rendering code
+    /// without granting access to the mutating methods.
+    /// 
+    /// # Returns
+    /// * `ToDoListView`: View borrowing the ToDoList
+    pub fn view(&self) -> ToDoListView<'_> {
+        ToDoListView { list: self }
+    }
+
+    /// Compares the ToDoList with another version of it, e.g. the last saved state.
+    /// Items are matched by their names. An Item is considered added if it only exists in
+    /// `self`, removed if it only exists in `other`, and modified if both versions differ.
+    ///
+    /// # Arguments
+    /// * other : &ToDoList - Version of the list to compare against
+    ///
+    /// # Returns
+    /// * `ListDiff`: Sorted names of the added, removed, and modified Items
+    pub fn diff(&self, other: &ToDoList) -> ListDiff {
+        let mut diff = ListDiff::default();
+        for (name, item) in Self::list_all_items(&self.items) {
+            match other.items.get(name) {
+                None => diff.added.push(name.clone()),
+                Some(other_item) if other_item != item => diff.modified.push(name.clone()),
+                Some(_) => {}
+            }
+        }
+        for (name, _) in Self::list_all_items(&other.items) {
+            if !self.list_contains_item(name) {
+                diff.removed.push(name.clone());
+            }
+        }
+        diff
+    }
+
+    /// Converts an item HashMap into a Vector in which the original entries are
+    /// stored in tuples. The items in the resulting vector are sorted by their
+    /// position in the custom order. Items with the same position are sorted alphabetically.
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Sorted Vector representing the inserted HashMap
+    pub fn sort_items_by_order(hash_map: &HashMap<String, Item>) -> Vec<(&String, &Item)> {
+        let mut output = sort_list(hash_map);
+        output.sort_by_key(|item| item.1.order);
+        output
+    }
+
+    /// Sorts Item tuples by their importance. Pinned Items come first, followed by overdue
+    /// Items. Afterwards, Items are ordered by descending priority, then by ascending due date
+    /// (Items without a due date last), and finally alphabetically by name.
+    /// 
+    /// # Arguments
+    /// * items : &mut [(&String, &Item)] - Item tuples to sort in place
+    fn smart_sort(items: &mut [(&String, &Item)]) {
+        items.sort_by(|x, y| {
+            y.1.pinned.cmp(&x.1.pinned)
+                .then_with(|| y.1.is_overdue().cmp(&x.1.is_overdue()))
+                .then_with(|| y.1.priority.cmp(&x.1.priority))
+                .then_with(|| x.1.due_date.is_none().cmp(&y.1.due_date.is_none()))
+                .then_with(|| x.1.due_date.cmp(&y.1.due_date))
+                .then_with(|| x.0.cmp(y.0))
+        });
+    }
+
+    /// Returns the most important open Items to keep the focus on a limited number of tasks.
+    /// The Items are ordered by the smart sort order: pinned, overdue, priority, due date, and name.
+    /// 
+    /// # Arguments
+    /// * n : usize - Maximum number of returned Items
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: The first n open Items in the smart sort order
+    pub fn top_open_items(&self, n: usize) -> Vec<(&String, &Item)> {
+        let mut output: Vec<(&String, &Item)> = self.items.iter().filter(|item| !item.1.is_completed()).collect();
+        Self::smart_sort(&mut output);
+        output.truncate(n);
+        output
+    }
+
+    /// Returns the open Items whose priority is at least the submitted threshold.
+    /// 
+    /// # Arguments
+    /// * priority : &Priority - Lowest priority of the returned Items
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Matching Items sorted by descending priority and then by name
+    pub fn items_at_least(&self, priority: &Priority) -> Vec<(&String, &Item)> {
+        let mut output: Vec<(&String, &Item)> = self.items
+            .iter()
+            .filter(|item| !item.1.is_completed() && item.1.priority >= *priority)
+            .collect();
+        output.sort_by(|x, y| y.1.priority.cmp(&x.1.priority).then_with(|| x.0.cmp(y.0)));
+        output
+    }
+
+    /// Returns the open Items whose due date passed the longest time ago, e.g. for triage.
+    /// Items with the same due date are sorted alphabetically by name.
+    /// 
+    /// # Arguments
+    /// * n : usize - Maximum number of returned Items
     /// 
     /// # Returns
-    /// * `Vec<(&String, &Item)>`: Sorted Vector representing the inserted HashMap      
-    pub fn list_all_items (hash_map: &HashMap<String, Item>) -> Vec<(&String, &Item)> {
-        sort_list(hash_map)
-    }         
+    /// * `Vec<(&String, &Item)>`: Up to n overdue Items, most overdue first
+    pub fn top_overdue(&self, n: usize) -> Vec<(&String, &Item)> {
+        let mut output: Vec<(&String, &Item)> = self.items
+            .iter()
+            .filter(|item| !item.1.is_completed() && item.1.is_overdue())
+            .collect();
+        output.sort_by(|x, y| x.1.due_date.cmp(&y.1.due_date).then_with(|| x.0.cmp(y.0)));
+        output.truncate(n);
+        output
+    }
+
+    /// Splits the Items into open and completed Items, e.g. for a two-column board.
+    /// Both groups are sorted alphabetically by name.
+    /// 
+    /// # Returns
+    /// * `(ItemEntries, ItemEntries)`: The open Items and the completed Items
+    pub fn partition_by_status(&self) -> (ItemEntries<'_>, ItemEntries<'_>) {
+        sort_list(&self.items).into_iter().partition(|item| !item.1.is_completed())
+    }
+
+    /// Returns the open Item that has been open the longest, regardless of its due date.
+    /// Items created on the same day are compared by name.
+    /// 
+    /// # Returns
+    /// * `Option<(&String, &Item)>`: The open Item with the earliest creation date or `None` if no Item is open
+    pub fn oldest_open_item(&self) -> Option<(&String, &Item)> {
+        self.items.iter()
+            .filter(|item| !item.1.is_completed())
+            .min_by(|x, y| x.1.creation_date.cmp(&y.1.creation_date).then_with(|| x.0.cmp(y.0)))
+    }
+
+    /// Sorts all Items by their creation date, starting with the oldest Item.
+    /// Items created on the same day are sorted alphabetically by name.
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Items ordered from oldest to newest
+    pub fn sort_items_by_age(&self) -> Vec<(&String, &Item)> {
+        let mut output = sort_list(&self.items);
+        // The sort is stable, so the alphabetical order is kept for Items of the same age
+        output.sort_by_key(|item| item.1.creation_date);
+        output
+    }
+
+    /// Prints every Item in the ToDoList to the console, starting with the oldest Item.
+    pub fn display_all_items_by_age(&self) {
+        for item in self.sort_items_by_age() {
+            println!("\n{}", self.format_item(item.1));
+        }
+    }
+
+    /// Prints every Item in the ToDoList to the console in the custom order.
+    pub fn display_items_by_order(&self) {
+        let list = Self::sort_items_by_order(&self.items);
+        for item in list {
+            println!("\n{}", self.format_item(item.1));
+        }
+    }
+
+    /// Sorts all Items according to the sort order of the ToDoList. Pinned Items are
+    /// always placed above all other Items and ties are sorted alphabetically by name.
+    /// If completed Items are set to be displayed last, they are moved below all open
+    /// Items while both groups keep the sort order.
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Items in the sort order of the list
+    pub fn sort_items(&self) -> Vec<(&String, &Item)> {
+        let mut output = Self::list_all_items(&self.items);
+        // The sort is stable, so pinned Items and the alphabetical order are kept for ties
+        match self.sort_order {
+            SortOrder::Alphabetical => {}
+            SortOrder::Priority => output.sort_by(|x, y| y.1.pinned.cmp(&x.1.pinned).then_with(|| y.1.priority.cmp(&x.1.priority))),
+            SortOrder::DueDate => output.sort_by(|x, y| {
+                y.1.pinned.cmp(&x.1.pinned)
+                    .then_with(|| x.1.due_date.is_none().cmp(&y.1.due_date.is_none()))
+                    .then_with(|| x.1.due_date.cmp(&y.1.due_date))
+            }),
+            SortOrder::Age => output.sort_by(|x, y| y.1.pinned.cmp(&x.1.pinned).then_with(|| x.1.creation_date.cmp(&y.1.creation_date))),
+        }
+        if self.completed_last {
+            output.sort_by_key(|item| item.1.completed);
+        }
+        output
+    }
 
-    /// Prints every Item in the ToDoList to the console.
+    /// Prints every Item in the ToDoList to the console in the sort order of the list.
     pub fn display_all_items(&self) {
-        let list = Self::list_all_items(&self.items);
+        let list = self.sort_items();
         for item in list {
-            println!("\n{}", item.1);
+            println!("\n{}", self.format_item(item.1));
+        }
+    }
+
+    /// Prints every Item in the ToDoList to the console with a leading number.
+    /// The numbers start at 1 and follow the sort order of the list, see `sort_items`.
+    ///
+    /// # Returns
+    /// * `Vec<String>`: Names of the printed Items in the displayed order
+    pub fn display_numbered_items(&self) -> Vec<String> {
+        let list = self.sort_items();
+        let mut item_names: Vec<String> = vec![];
+        for (index, item) in list.into_iter().enumerate() {
+            println!("\n{}: {}", index + 1, self.format_item(item.1));
+            item_names.push(item.0.clone());
+        }
+        item_names
+    }
+
+    /// Collects the names of all Items without cloning the Items.
+    /// The names follow the same alphabetical order as `list_all_items`.
+    /// 
+    /// # Returns
+    /// * `Vec<&String>`: Alphabetically sorted Item names
+    pub fn item_names(&self) -> Vec<&String> {
+        let mut names: Vec<&String> = self.items.keys().collect();
+        names.sort_by(|x, y| compare_folded(x, y));
+        names
+    }
+
+    /// Collects the distinct tags of all Items.
+    /// 
+    /// # Returns
+    /// * `Vec<String>`: Alphabetically sorted tags
+    pub fn all_tags(&self) -> Vec<String> {
+        let tags: BTreeSet<&String> = self.items.values().flat_map(|item| item.tags.iter()).collect();
+        tags.into_iter().cloned().collect()
+    }
+
+    /// Counts the open Items of every assignee. Open Items without an assignee
+    /// are counted under `UNASSIGNED`.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, usize>`: Number of open Items per assignee
+    pub fn workload_by_assignee(&self) -> HashMap<String, usize> {
+        let mut workload: HashMap<String, usize> = HashMap::new();
+        for item in self.items.values().filter(|item| !item.is_completed()) {
+            let assignee = item.assignee.as_deref().unwrap_or(UNASSIGNED);
+            *workload.entry(assignee.to_string()).or_insert(0) += 1;
+        }
+        workload
+    }
+
+    /// Picks the next action for every tag in a GTD style: the open Item with the highest
+    /// priority that carries the tag. Ties are broken by the earlier due date (Items without
+    /// a due date last) and then by name.
+    /// 
+    /// # Returns
+    /// * `HashMap<String, &Item>`: Next action for every tag that has at least one open Item
+    pub fn next_actions_by_tag(&self) -> HashMap<String, &Item> {
+        let mut open_items: Vec<(&String, &Item)> = self.items.iter().filter(|item| !item.1.is_completed()).collect();
+        open_items.sort_by(|x, y| {
+            y.1.priority.cmp(&x.1.priority)
+                .then_with(|| x.1.due_date.is_none().cmp(&y.1.due_date.is_none()))
+                .then_with(|| x.1.due_date.cmp(&y.1.due_date))
+                .then_with(|| x.0.cmp(y.0))
+        });
+        let mut output: HashMap<String, &Item> = HashMap::new();
+        for (_, item) in open_items {
+            for tag in &item.tags {
+                output.entry(tag.clone()).or_insert(item);
+            }
+        }
+        output
+    }
+
+    /// Renders all Items grouped under their tags. Every tag is printed as a heading followed
+    /// by its Items. Items with multiple tags appear under each of them, and Items without any
+    /// tag are listed in a final "Untagged" section.
+    /// 
+    /// # Returns
+    /// * `String`: One heading line per group followed by one line per Item
+    pub fn format_grouped_by_tag(&self) -> String {
+        let mut output = String::new();
+        let list = Self::list_all_items(&self.items);
+        for tag in self.all_tags() {
+            output.push_str(&format!("Tag: {}\n", tag));
+            for (_, item) in list.iter().filter(|item| item.1.tags.contains(&tag)) {
+                output.push_str(&format!("\t{}\n", self.format_item(item)));
+            }
+        }
+        let untagged: Vec<_> = list.iter().filter(|item| item.1.tags.is_empty()).collect();
+        if !untagged.is_empty() {
+            output.push_str("Untagged\n");
+            for (_, item) in untagged {
+                output.push_str(&format!("\t{}\n", self.format_item(item)));
+            }
+        }
+        output
+    }
+
+    /// Prints every Item in the ToDoList to the console grouped under its tags.
+    pub fn display_grouped_by_tag(&self) {
+        print!("{}", self.format_grouped_by_tag());
+    }
+
+    /// Groups all Items with a due date by the ISO week of their due date.
+    /// Items without a due date are skipped. Within a week, the Items are sorted by
+    /// due date and then by name.
+    /// 
+    /// # Returns
+    /// * `BTreeMap<(i32, u32), Vec<&Item>>`: Items by ISO year and ISO week number
+    pub fn group_by_due_week(&self) -> BTreeMap<(i32, u32), Vec<&Item>> {
+        let mut weeks: BTreeMap<(i32, u32), Vec<&Item>> = BTreeMap::new();
+        for (_, item) in Self::list_all_items(&self.items) {
+            if let Some(due_date) = item.due_date {
+                let week = due_date.iso_week();
+                weeks.entry((week.year(), week.week())).or_default().push(item);
+            }
+        }
+        for items in weeks.values_mut() {
+            items.sort_by_key(|item| item.due_date);
+        }
+        weeks
+    }
+
+    /// Renders all Items with a due date grouped by the ISO week of their due date.
+    /// Every week is printed as a heading followed by its Items. See `group_by_due_week`.
+    /// 
+    /// # Returns
+    /// * `String`: One heading line per week followed by one line per Item
+    pub fn format_grouped_by_due_week(&self) -> String {
+        let mut output = String::new();
+        for ((year, week), items) in self.group_by_due_week() {
+            output.push_str(&format!("Week {} of {}\n", week, year));
+            for item in items {
+                output.push_str(&format!("\t{}\n", self.format_item(item)));
+            }
+        }
+        output
+    }
+
+    /// Prints every Item with a due date to the console grouped by the week of its due date.
+    pub fn display_grouped_by_due_week(&self) {
+        print!("{}", self.format_grouped_by_due_week());
+    }
+
+    /// Renders all Items as a tree in which child Items are indented beneath their parents.
+    /// Items without an existing parent are rendered as top-level Items. Items that are part
+    /// of a parent cycle are rendered only once.
+    /// 
+    /// # Returns
+    /// * `String`: One line per Item, indented by its depth in the hierarchy
+    pub fn format_item_tree(&self) -> String {
+        let mut output = String::new();
+        let mut visited: HashSet<&str> = HashSet::new();
+        let list = Self::list_all_items(&self.items);
+        for (_, item) in &list {
+            let is_root = match &item.parent {
+                Some(parent) => !self.list_contains_item(parent),
+                None => true,
+            };
+            if is_root {
+                self.format_item_subtree(item, 0, &mut visited, &mut output);
+            }
         }
+        // Items in a parent cycle have no root and are rendered as top-level Items instead
+        for (_, item) in &list {
+            if !visited.contains(item.name.as_str()) {
+                self.format_item_subtree(item, 0, &mut visited, &mut output);
+            }
+        }
+        output
+    }
+
+    /// Appends an Item and all its unvisited descendants to the tree output.
+    /// 
+    /// # Arguments
+    /// * item : &Item - Item to render
+    /// * depth : usize - Indentation level of the Item
+    /// * visited : &mut HashSet<&str> - Names of all Items that were already rendered
+    /// * output : &mut String - Tree output
+    fn format_item_subtree<'a>(&'a self, item: &'a Item, depth: usize, visited: &mut HashSet<&'a str>, output: &mut String) {
+        if !visited.insert(item.name.as_str()) {
+            return;
+        }
+        output.push_str(&format!("{}{}\n", "    ".repeat(depth), self.format_item(item)));
+        for child in self.children_of(&item.name) {
+            self.format_item_subtree(child, depth + 1, visited, output);
+        }
+    }
+
+    /// Prints every Item in the ToDoList to the console with child Items
+    /// indented beneath their parents.
+    pub fn display_item_tree(&self) {
+        print!("{}", self.format_item_tree());
     }
 
     /// Prints every non-completed Item in the ToDoList to the console.
@@ -425,19 +2909,134 @@ impl ToDoList {
         let filtered_list = self.filter_open_items();
         let list = Self::list_all_items(&filtered_list);
         for item in list {
-            println!("\n{}", item.1);
+            println!("\n{}", self.format_item(item.1));
         }
     }    
 
+    /// Prints every Item whose reminder is due today to the console.
+    pub fn display_reminders_due_today(&self) {
+        let filtered_list = self.reminders_due_today();
+        let list = Self::list_all_items(&filtered_list);
+        for item in list {
+            println!("\n{}", self.format_item(item.1));
+        }
+    }
+
     /// Prints every overdue Item in the ToDoList to the console.
     pub fn display_all_overdue_items(&self) {
         let filtered_list = self.filter_overdue_items();
         let list = Self::list_all_items(&filtered_list);
         for item in list {
-            println!("\n{}", item.1);
+            println!("\n{}", self.format_item(item.1));
         }
     }
 
+    /// Exports the `ToDoList` as a minimal iCalendar (.ics) document with one VTODO per Item.
+    /// Items without a due date are exported without a DUE property. Completed Items
+    /// receive the COMPLETED status, all others the NEEDS-ACTION status.
+    /// 
+    /// # Returns
+    /// * `String`: The iCalendar document using CRLF line endings
+    pub fn export_ics(&self) -> String {
+        let mut lines: Vec<String> = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//to_do_list//EN".to_string(),
+        ];
+        for (name, item) in Self::list_all_items(&self.items) {
+            lines.push("BEGIN:VTODO".to_string());
+            lines.push(format!("UID:{}@{}", escape_ics_text(name), escape_ics_text(&self.name)));
+            lines.push(format!("DTSTAMP:{}T000000Z", item.creation_date.format("%Y%m%d")));
+            lines.push(format!("SUMMARY:{}", escape_ics_text(name)));
+            lines.push(format!("DESCRIPTION:{}", escape_ics_text(&item.description)));
+            if let Some(due_date) = item.due_date {
+                lines.push(format!("DUE;VALUE=DATE:{}", due_date.format("%Y%m%d")));
+            }
+            let status = if item.completed { "COMPLETED" } else { "NEEDS-ACTION" };
+            lines.push(format!("STATUS:{}", status));
+            lines.push("END:VTODO".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+        lines.join("\r\n") + "\r\n"
+    }
+
+    /// Exports the `ToDoList` as an HTML page for sharing a read-only snapshot. The page shows
+    /// the name and description of the list as headings and a table with the name, priority,
+    /// due date, and status of every Item. Overdue Items receive the `overdue` CSS class.
+    /// 
+    /// # Returns
+    /// * `String`: The HTML document
+    pub fn export_html(&self) -> String {
+        let mut lines: Vec<String> = vec![
+            "<!DOCTYPE html>".to_string(),
+            "<html>".to_string(),
+            "<head>".to_string(),
+            "<meta charset=\"utf-8\">".to_string(),
+            format!("<title>{}</title>", escape_html(&self.name)),
+            "<style>.overdue { color: #b00020; }</style>".to_string(),
+            "</head>".to_string(),
+            "<body>".to_string(),
+            format!("<h1>{}</h1>", escape_html(&self.name)),
+            format!("<h2>{}</h2>", escape_html(&self.description)),
+            "<table>".to_string(),
+            "<tr><th>Name</th><th>Priority</th><th>Due Date</th><th>Status</th></tr>".to_string(),
+        ];
+        for (name, item) in Self::list_all_items(&self.items) {
+            let row_start = if !item.completed && item.is_overdue() { "<tr class=\"overdue\">" } else { "<tr>" };
+            let status = if item.completed { "&#9745; Completed" } else { "&#9744; Open" };
+            lines.push(format!(
+                "{}<td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                row_start, escape_html(name), item.priority, item.due_date_string(), status
+            ));
+        }
+        lines.push("</table>".to_string());
+        lines.push("</body>".to_string());
+        lines.push("</html>".to_string());
+        lines.join("\n") + "\n"
+    }
+
+    /// Serializes a single Item of the `ToDoList` as JSON. See `Item::to_json`.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the Item
+    /// 
+    /// # Returns
+    /// * `String`: Pretty-printed JSON representation of the Item
+    /// 
+    /// # Errors
+    /// * Returns an error message if the Item does not exist or cannot be serialized
+    pub fn export_item(&self, name: &str) -> Result<String, String> {
+        let item = self.get_item_ref(name).map_err(|e| e.to_string())?;
+        item.to_json().map_err(|e| format!("JSON serialize error: {}", e))
+    }
+
+    /// Writes all open overdue Items of the `ToDoList` as a JSON object to a file.
+    /// The Items are keyed by their names in alphabetical order. See `filter_overdue_items`.
+    /// 
+    /// # Arguments
+    /// * path : &Path - Path of the file that is created or overwritten
+    /// 
+    /// # Errors
+    /// * Returns an error if the Items cannot be serialized or the file cannot be written
+    pub fn export_overdue(&self, path: &Path) -> Result<(), io::Error> {
+        let overdue: BTreeMap<String, Item> = self.filter_overdue_items().into_iter().collect();
+        let json = serde_json::to_string_pretty(&overdue).map_err(io::Error::other)?;
+        write(path, json)
+    }
+
+    /// Converts the `ToDoList` and all its Items into a JSON value without writing a file.
+    /// The value has the same structure as the saved JSON file and can be used for
+    /// pointer queries or further transformations.
+    /// 
+    /// # Returns
+    /// * `serde_json::Value`: JSON representation of the ToDoList
+    /// 
+    /// # Panics
+    /// The method will panic if the ToDoList cannot be converted to a JSON value.
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Unable to convert the list to JSON")
+    }
+
     /// Permanently save the `ToDoList` and all its Items to a JSON file. 
     /// The file will be generated in the ./lists folder.
     /// 
@@ -445,9 +3044,34 @@ impl ToDoList {
     /// The method will panic if the ToDoList cannot be converted to a JSON file or
     /// if the expected lists folder cannot be found.
     pub fn save_to_do_list(&self) {
-        let json = serde_json::to_string_pretty(self).expect("JSON serialize error");
-        let path = format!("./lists/{}.json", self.name);
-        write(path, json).expect("Unable to write file");
+        self.save_to_storage(&FileStorage::new("./lists")).expect("Unable to write file");
+    }
+
+    /// Save the `ToDoList` and all its Items as JSON in a storage backend.
+    /// The list is stored under its name.
+    /// 
+    /// # Arguments
+    /// * storage : &dyn Storage - Storage backend to save the list in
+    /// 
+    /// # Errors
+    /// * Returns an error message if the list cannot be serialized or stored
+    pub fn save_to_storage(&self, storage: &dyn Storage) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| format!("JSON serialize error: {}", e))?;
+        storage.save(&self.name, &json)
+    }
+
+    /// Load an existing `ToDoList` and its Items from a storage backend.
+    /// 
+    /// # Arguments
+    /// * storage : &dyn Storage - Storage backend to load the list from
+    /// * list_name : &str - Name of the list
+    /// 
+    /// # Errors
+    /// * Returns an error message if the list does not exist in the storage or cannot be deserialized
+    pub fn load_from_storage(storage: &dyn Storage, list_name: &str) -> Result<Self, String> {
+        let json = storage.load(list_name)?;
+        let list: Self = serde_json::from_str(&json).map_err(|e| format!("Could not process JSON of list {}: {}", list_name, e))?;
+        Ok(list.check_loaded_priorities())
     }
 
     /// Load an existing `ToDoList` and its Items from an JSON file. 
@@ -463,9 +3087,68 @@ impl ToDoList {
             format!("./lists/{}.json", list_name)
         };
         let file = File::open(&path).expect("Could not open the file");
-        serde_json::from_reader(file).expect("Could not process JSON file")
+        let list: Self = serde_json::from_reader(file).expect("Could not process JSON file");
+        list.check_loaded_priorities()
     }    
 
+    /// Load an existing `ToDoList` and its Items from a JSON file at any location.
+    /// 
+    /// # Arguments
+    /// * path : &Path - Path of the JSON file
+    /// 
+    /// # Errors
+    /// * Returns an error message if the file cannot be opened or does not contain a ToDoList
+    pub fn load_from_path(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("Could not open the file {}: {}", path.display(), e))?;
+        let list: Self = serde_json::from_reader(file).map_err(|e| format!("Could not process JSON file {}: {}", path.display(), e))?;
+        Ok(list.check_loaded_priorities())
+    }
+
+    /// Reports Items with an Invalid priority in a freshly loaded `ToDoList` and replaces
+    /// their priority with Low if the list normalizes priorities on load.
+    /// 
+    /// # Returns
+    /// * `ToDoList`: The checked list
+    fn check_loaded_priorities(mut self) -> Self {
+        let invalid_count = self.items.values().filter(|item| item.priority == Priority::Invalid).count();
+        if invalid_count > 0 {
+            println!("Warning: The list {} contains {} item(s) with an invalid priority", self.name, invalid_count);
+            if self.normalize_on_load && self.normalize_priorities() > 0 {
+                println!("The invalid priorities were set to Low");
+            }
+        }
+        self
+    }
+
+}
+
+/// Escapes a text value so it can be used inside an iCalendar property.
+/// 
+/// # Arguments
+/// * text : &str - Unescaped text
+/// 
+/// # Returns
+/// * `String`: Escaped text
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Escapes the HTML special characters of a text so it can be used inside an HTML element.
+/// 
+/// # Arguments
+/// * text : &str - Unescaped text
+/// 
+/// # Returns
+/// * `String`: Escaped text
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
 }
 
 impl Display for ToDoList {
@@ -473,3 +3156,217 @@ impl Display for ToDoList {
         write!(f, "Name: {}\tDescription: {}", self.name, self.description)
     }
 }
+
+/// Read-only view of a `ToDoList` created with `ToDoList::view`.
+/// The view only exposes accessors, so the borrowed list cannot be changed through it.
+#[derive(Debug, Clone, Copy)]
+pub struct ToDoListView<'a> {
+    /// Borrowed list
+    list: &'a ToDoList,
+}
+
+impl<'a> ToDoListView<'a> {
+    /// Creates a reference to the name of the viewed list.
+    /// 
+    /// # Returns
+    /// * `&str`: Name of the list
+    pub fn name(&self) -> &'a str {
+        &self.list.name
+    }
+
+    /// Creates a reference to the description of the viewed list.
+    /// 
+    /// # Returns
+    /// * `&str`: Description of the list
+    pub fn description(&self) -> &'a str {
+        &self.list.description
+    }
+
+    /// Collects all Items of the viewed list in its display order. See `ToDoList::sort_items`.
+    /// 
+    /// # Returns
+    /// * `Vec<(&String, &Item)>`: Sorted Items of the list
+    pub fn items(&self) -> Vec<(&'a String, &'a Item)> {
+        self.list.sort_items()
+    }
+
+    /// Counts the Items of the viewed list by their state. See `ToDoList::stats`.
+    /// 
+    /// # Returns
+    /// * `ListStats`: Number of total, open, completed, and overdue Items
+    pub fn stats(&self) -> ListStats {
+        self.list.stats()
+    }
+}
+
+/// Set of field changes that can be applied to an `Item` with `ToDoList::apply_update`.
+/// Fields that are `None` are left unchanged.
+#[derive(Debug, Default, Clone)]
+pub struct ItemUpdate {
+    /// New description of the Item
+    pub description: Option<String>,
+    /// New priority of the Item (low/medium/high/critical)
+    pub priority: Option<String>,
+    /// New due date of the Item (year, month, day)
+    pub due_date: Option<(i32, u32, u32)>,
+    /// New completion state of the Item
+    pub completed: Option<bool>,
+}
+
+/// Builder to construct an `Item` step by step instead of with positional arguments.
+/// Fields that are not set keep their defaults: an empty name and description,
+/// the Medium priority, no due date, and no tags.
+#[derive(Debug, Default, Clone)]
+pub struct ItemBuilder {
+    /// Name of the Item
+    name: String,
+    /// Description of the Item
+    description: String,
+    /// Priority of the Item (low/medium/high/critical)
+    priority: Option<String>,
+    /// Due date of the Item (year, month, day)
+    due_date: Option<(i32, u32, u32)>,
+    /// Tags of the Item
+    tags: Vec<String>,
+}
+
+impl ItemBuilder {
+    /// Constructor function for a new `ItemBuilder` without any fields set.
+    /// 
+    /// # Returns
+    /// * `ItemBuilder`: A new builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the Item.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the Item
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Sets the description of the Item.
+    /// 
+    /// # Arguments
+    /// * description : &str - Item description
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Sets the priority of the Item. See `Priority::from_str` for permissable values.
+    /// 
+    /// # Arguments
+    /// * priority : &str - Item priority
+    pub fn priority(mut self, priority: &str) -> Self {
+        self.priority = Some(priority.to_string());
+        self
+    }
+
+    /// Sets the due date of the Item. Invalid dates are rejected when the Item is built.
+    /// 
+    /// # Arguments
+    /// * ymd : (i32, u32, u32) - Item due date (year, month, day)
+    pub fn due_date(mut self, ymd: (i32, u32, u32)) -> Self {
+        self.due_date = Some(ymd);
+        self
+    }
+
+    /// Adds a tag to the Item. The method can be called multiple times to add several tags.
+    /// 
+    /// # Arguments
+    /// * tag : &str - Tag to add
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Creates the `Item` from the fields set on the builder.
+    /// 
+    /// # Returns
+    /// * `Item`: A new, non-completed Item
+    /// 
+    /// # Errors
+    /// * `ToDoSelectionError::InvalidDate`: The due date set on the builder is not a valid date.
+    pub fn build(self) -> Result<Item, ToDoSelectionError> {
+        let priority = self.priority.unwrap_or_else(|| Priority::Medium.to_string());
+        let mut item = Item::new(&self.name, &self.description, &priority, self.due_date)?;
+        for tag in &self.tags {
+            item.add_tag(tag);
+        }
+        Ok(item)
+    }
+}
+
+/// Number of Items in a `ToDoList` by their state.
+#[derive(Debug, Default, PartialEq)]
+pub struct ListStats {
+    /// Number of all Items
+    pub total: usize,
+    /// Number of non-completed Items
+    pub open: usize,
+    /// Number of completed Items
+    pub completed: usize,
+    /// Number of non-completed Items whose due date passed
+    pub overdue: usize,
+    /// Number of non-completed Items due within the next 7 days
+    pub due_this_week: usize,
+}
+
+impl Display for ListStats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Total: {}\tOpen: {}\tCompleted: {}\tOverdue: {}\tDue this week: {}", self.total, self.open, self.completed, self.overdue, self.due_this_week)
+    }
+}
+
+/// Number of open Items in a `ToDoList` by the state of their due date.
+#[derive(Debug, Default, PartialEq)]
+pub struct DueBreakdown {
+    /// Number of open Items whose due date passed
+    pub overdue: usize,
+    /// Number of open Items due today
+    pub due_today: usize,
+    /// Number of open Items due after today
+    pub upcoming: usize,
+    /// Number of open Items without a due date
+    pub no_date: usize,
+}
+
+impl Display for DueBreakdown {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Overdue: {}\tDue today: {}\tUpcoming: {}\tNo due date: {}", self.overdue, self.due_today, self.upcoming, self.no_date)
+    }
+}
+
+/// Summary of the differences between two versions of a `ToDoList`.
+#[derive(Debug, Default, PartialEq)]
+pub struct ListDiff {
+    /// Names of Items that only exist in the newer version
+    pub added: Vec<String>,
+    /// Names of Items that only exist in the older version
+    pub removed: Vec<String>,
+    /// Names of Items that exist in both versions but differ
+    pub modified: Vec<String>,
+}
+
+impl ListDiff {
+    /// Indicates whether both compared versions contain the same Items.
+    /// 
+    /// # Returns
+    /// * `bool`: Is `true` if no Item was added, removed, or modified
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl Display for ListDiff {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No changes");
+        }
+        write!(f, "Added: {}\nRemoved: {}\nModified: {}", self.added.join(", "), self.removed.join(", "), self.modified.join(", "))
+    }
+}
@@ -1,24 +1,54 @@
 use to_do_list::{
     get_user_input, 
+    get_number_input,
     visualize_lists, 
-    show_all_lists, 
+    show_all_lists_with_counts, 
     open_to_do_list, 
     modify_to_do_list, 
-    create_to_do_list
+    create_to_do_list,
+    manage_backups,
+    build_today_list,
+    open_lists_folder,
+    show_all_lists_detailed,
+    restore_backup,
+    vacuum_list,
+    manage_trash,
+    empty_trash,
+    first_run_setup,
+    aggregate_counts,
+    open_most_recent,
+    request_interrupt,
+    is_interrupted,
+    confirm,
+    set_assume_yes,
+    compare_lists,
+    show_lists_by_category
 };
 
 fn main() {
     println!("Welcome to your To-Do Lists.");
+    // Pre-answer all confirmation prompts when started with --yes or -y
+    set_assume_yes(std::env::args().skip(1).any(|arg| arg == "--yes" || arg == "-y"));
+    // Ctrl-C asks the open menus to save and return, a second Ctrl-C exits immediately
+    if let Err(e) = ctrlc::set_handler(|| {
+        if is_interrupted() {
+            std::process::exit(130);
+        }
+        request_interrupt();
+        println!("\nInterrupted. Press Enter to save the open list and exit");
+    }) {
+        println!("The interrupt handler could not be installed: {}", e);
+    }
+    first_run_setup();
     'main: loop {
-        println!("\nPlease make a selection:\n1: Examine existing lists\n2: Create a new list\n3: View/Update an existing list\n4: Delete list\n5: Exit");
-        let input = get_user_input();
-        let input: u32 = match input.trim().parse() {
-            Ok(num) => num,
-            Err(_) => {
-                println!("Please enter a number");
-                continue;
-            }
-        };       
+        let (list_count, _, open, overdue) = aggregate_counts();
+        println!("\n{} lists, {} open, {} overdue", list_count, open, overdue);
+        println!("Please make a selection:\n1: Examine existing lists\n2: Create a new list\n3: View/Update an existing list\n4: Delete list\n5: Clean backup files\n6: Show items due today\n7: Open lists folder\n8: Show list overview\n9: Restore list from backup\n10: Compact a list\n11: Restore item from trash\n12: Empty trash\n13: Resume most recent list\n14: Compare two lists\n15: Show lists by category\n16: Exit");
+        let input: u32 = match get_number_input() {
+            Some(num) => num,
+            None if is_interrupted() => break 'main,
+            None => continue 'main,
+        };
         if input == 1 {
             visualize_lists();
         }
@@ -29,8 +59,11 @@ fn main() {
             'list_selection: loop {
                 println!("Please enter the name of the list you would like to open");
                 println!("Or enter 'cancel' to return");
-                show_all_lists();
+                show_all_lists_with_counts(false);
                 let input  = get_user_input();
+                if is_interrupted() {
+                    break 'main;
+                }
                 if input.to_lowercase().trim().eq("cancel") {
                     break 'list_selection;
                 }
@@ -43,6 +76,62 @@ fn main() {
             }
         }
         if input == 5 {
+            manage_backups();
+        }
+        if input == 6 {
+            let today_list = build_today_list();
+            println!("{}", today_list);
+            today_list.display_all_items();
+        }
+        if input == 7 && let Err(e) = open_lists_folder() {
+            println!("{}", e);
+        }
+        if input == 8 {
+            show_all_lists_detailed();
+        }
+        if input == 9 {
+            println!("Please enter the name of the list to restore");
+            match restore_backup(get_user_input().trim()) {
+                Ok(_) => println!("The list was restored"),
+                Err(e) => println!("{}", e),
+            }
+        }
+        if input == 10 {
+            println!("Please enter the name of the list to compact");
+            if let Err(e) = vacuum_list(get_user_input().trim()) {
+                println!("{}", e);
+            }
+        }
+        if input == 11 {
+            manage_trash();
+        }
+        if input == 12 && confirm("All items in the trash will be deleted permanently. Enter 'Y' to confirm") {
+            match empty_trash() {
+                Ok(count) => println!("{} item(s) were deleted permanently", count),
+                Err(e) => println!("{}", e),
+            }
+        }
+        if input == 13 {
+            match open_most_recent() {
+                Ok(list) => modify_to_do_list(list),
+                Err(e) => println!("{}", e),
+            }
+        }
+        if input == 14 {
+            println!("Please enter the name of the first list");
+            let first_list = get_user_input();
+            println!("Please enter the name of the second list");
+            let second_list = get_user_input();
+            match compare_lists(first_list.trim(), second_list.trim()) {
+                Ok(report) => println!("{}", report),
+                Err(e) => println!("{}", e),
+            }
+        }
+        if input == 15 {
+            println!("Please enter the category");
+            show_lists_by_category(&get_user_input());
+        }
+        if input == 16 {
             break 'main;
         }
     }
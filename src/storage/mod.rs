@@ -0,0 +1,7 @@
+//! # Storage
+//!
+//! The module defines how to-do lists are persisted. The `Storage` trait abstracts the
+//! underlying medium so that lists can be stored on disk or kept in memory (e.g. for tests).
+
+pub mod traits;
+pub mod structs;
@@ -0,0 +1,122 @@
+//! # Storage Structs
+//!
+//! The module defines the storage backends that implement the `Storage` trait.
+//! `FileStorage` keeps every list as a .json file in a folder and the test-only
+//! `MemoryStorage` keeps them in memory so that tests do not touch the disk.
+
+use crate::storage::traits::Storage;
+use crate::utils::functions::check_directory;
+#[cfg(test)]
+use std::cell::RefCell;
+#[cfg(test)]
+use std::collections::HashMap;
+use std::fs::{read_dir, read_to_string, remove_file, write};
+use std::path::PathBuf;
+
+/// Storage backend that keeps every list as a .json file in a folder.
+#[derive(Debug, Clone)]
+pub struct FileStorage {
+    /// Folder in which the list files are stored
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    /// Constructor function for a new `FileStorage`.
+    /// 
+    /// # Arguments
+    /// * dir : impl Into<PathBuf> - Folder in which the list files are stored
+    /// 
+    /// # Returns
+    /// * `FileStorage`: A new storage backend for the folder
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileStorage { dir: dir.into() }
+    }
+
+    /// Builds the path of the file that stores a list.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the list
+    /// 
+    /// # Returns
+    /// * `PathBuf`: Path of the list file
+    fn file_path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+impl Storage for FileStorage {
+    fn save(&self, name: &str, contents: &str) -> Result<(), String> {
+        check_directory(&self.dir).map_err(|e| format!("The list {} could not be saved: {}", name, e))?;
+        write(self.file_path(name), contents).map_err(|e| format!("The list {} could not be saved: {}", name, e))
+    }
+
+    fn load(&self, name: &str) -> Result<String, String> {
+        read_to_string(self.file_path(name)).map_err(|e| format!("The list {} could not be loaded: {}", name, e))
+    }
+
+    fn list(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        if let Ok(entries) = read_dir(&self.dir) {
+            for entry in entries.flatten() {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                if let Some(name) = file_name.strip_suffix(".json") {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+        names
+    }
+
+    fn delete(&self, name: &str) -> Result<(), String> {
+        remove_file(self.file_path(name)).map_err(|e| format!("The list {} could not be deleted: {}", name, e))
+    }
+}
+
+/// Storage backend that keeps all lists in memory. Nothing is written to disk.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    /// Serialized lists by list name
+    lists: RefCell<HashMap<String, String>>,
+}
+
+#[cfg(test)]
+impl MemoryStorage {
+    /// Constructor function for a new, empty `MemoryStorage`.
+    /// 
+    /// # Returns
+    /// * `MemoryStorage`: A new storage backend without any lists
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+impl Storage for MemoryStorage {
+    fn save(&self, name: &str, contents: &str) -> Result<(), String> {
+        self.lists.borrow_mut().insert(name.to_string(), contents.to_string());
+        Ok(())
+    }
+
+    fn load(&self, name: &str) -> Result<String, String> {
+        self.lists
+            .borrow()
+            .get(name)
+            .cloned()
+            .ok_or(format!("No list with the name {} is stored", name))
+    }
+
+    fn list(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.lists.borrow().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn delete(&self, name: &str) -> Result<(), String> {
+        match self.lists.borrow_mut().remove(name) {
+            Some(_) => Ok(()),
+            None => Err(format!("No list with the name {} is stored", name)),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! # Storage Traits
+//!
+//! The module defines the `Storage` trait that is implemented by every storage backend.
+
+/// Backend that persists serialized to-do lists under their list names.
+pub trait Storage {
+    /// Stores the serialized list under the submitted name. An existing list with the
+    /// same name is replaced.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the list
+    /// * contents : &str - Serialized list
+    /// 
+    /// # Errors
+    /// * Returns an error message if the list could not be stored
+    fn save(&self, name: &str, contents: &str) -> Result<(), String>;
+
+    /// Retrieves a serialized list.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the list
+    /// 
+    /// # Returns
+    /// * `String`: The serialized list
+    /// 
+    /// # Errors
+    /// * Returns an error message if no list with the submitted name exists or it could not be read
+    fn load(&self, name: &str) -> Result<String, String>;
+
+    /// Lists the names of all stored lists.
+    /// 
+    /// # Returns
+    /// * `Vec<String>`: Alphabetically sorted list names
+    fn list(&self) -> Vec<String>;
+
+    /// Permanently removes a stored list.
+    /// 
+    /// # Arguments
+    /// * name : &str - Name of the list
+    /// 
+    /// # Errors
+    /// * Returns an error message if no list with the submitted name exists or it could not be removed
+    fn delete(&self, name: &str) -> Result<(), String>;
+}
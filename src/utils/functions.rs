@@ -2,7 +2,29 @@
 //!
 //! Stores miscellenious functions that are not directly related to a single struct type.
 
+use chrono::{Datelike, Local, NaiveDate};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::path::Path;
+
+/// Describes a date relative to the current day, e.g. "today", "tomorrow",
+/// "in 3 days", "yesterday", or "5 days ago".
+/// 
+/// # Arguments
+/// * date : NaiveDate - Date to describe
+/// 
+/// # Returns
+/// * `String`: Relative description of the date
+pub fn humanize_date(date: NaiveDate) -> String {
+    let days = (date - Local::now().date_naive()).num_days();
+    match days {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "yesterday".to_string(),
+        days if days > 1 => format!("in {} days", days),
+        days => format!("{} days ago", -days),
+    }
+}
 
 /// Converts a HashMap into a Vector. The Key-Value pair will be stored as a tuple.
 /// In addition, the vector will be sorted alphabetically by the key values.
@@ -18,4 +40,93 @@ pub fn sort_list <T> (hash_list: &HashMap<String,T>) -> Vec<(&String, &T)> {
     let mut output: Vec<_> = hash_list.iter().collect();
     output.sort_by(|x,y| x.0.cmp(y.0));
     output
-}
\ No newline at end of file
+}
+
+/// Converts a character into its lowercase base letter by removing common accents.
+/// Characters without a known base letter are returned unchanged.
+/// 
+/// # Arguments
+/// * c: char - Lowercase character to fold
+/// 
+/// # Returns
+/// * `char`: The base letter of the character
+fn fold_accent(c: char) -> char {
+    match c {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ł' | 'ľ' | 'ĺ' | 'ļ' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => c,
+    }
+}
+
+/// Compares two strings the way a reader would expect in an alphabetical listing.
+/// Both strings are compared in lowercase with common accents removed, so "Éclair"
+/// is placed next to "eclair". Strings that are equal after folding are ordered by
+/// their Unicode code points to keep the order stable.
+/// 
+/// # Arguments
+/// * a: &str - First string
+/// * b: &str - Second string
+/// 
+/// # Returns
+/// * `Ordering`: Order of the first string relative to the second one
+pub fn compare_folded(a: &str, b: &str) -> Ordering {
+    let fold = |s: &str| -> String { s.to_lowercase().chars().map(fold_accent).collect() };
+    fold(a).cmp(&fold(b)).then_with(|| a.cmp(b))
+}
+
+/// Converts a HashMap into a Vector like `sort_list`, but sorts the keys with
+/// `compare_folded` instead of by their Unicode code points.
+/// 
+/// # Arguments
+/// * hash_list: &HashMap<String,T> - HashMap to be sorted and converted
+/// 
+/// # Returns
+/// * `Vec<(&String, &T)>`: The sorted Vector representation of the input HashMap
+pub fn sort_list_folded <T> (hash_list: &HashMap<String,T>) -> Vec<(&String, &T)> {
+    let mut output: Vec<_> = hash_list.iter().collect();
+    output.sort_by(|x,y| compare_folded(x.0, y.0));
+    output
+}
+
+/// Parses an ISO date string like `2024-05-01` into a tuple of year, month, and day.
+/// 
+/// # Arguments
+/// * input: &str - Date in the `YYYY-MM-DD` format
+/// 
+/// # Returns
+/// * `Option<(i32, u32, u32)>`: The year, month, and day or `None` if the input is not a valid ISO date
+pub fn parse_iso_date(input: &str) -> Option<(i32, u32, u32)> {
+    let date = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d").ok()?;
+    Some((date.year(), date.month(), date.day()))
+}
+
+/// Checks whether a path can be used as a folder. This distinguishes a missing folder from
+/// a file that was accidentally created where the folder is expected.
+/// 
+/// # Arguments
+/// * path: &Path - Path of the expected folder
+/// 
+/// # Errors
+/// * Returns an error message if the path does not exist or is not a directory
+pub fn check_directory(path: &Path) -> Result<(), String> {
+    if path.is_dir() {
+        Ok(())
+    } else if path.exists() {
+        Err(format!("'{}' exists but is not a directory. Please rename or remove the file.", path.display()))
+    } else {
+        Err(format!("The directory '{}' does not exist", path.display()))
+    }
+}